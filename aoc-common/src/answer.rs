@@ -0,0 +1,99 @@
+use std::fmt::{Display, Formatter};
+
+use crate::geometry::{IPoint, UPoint};
+
+/// Answer of a part, [`Answer::Unsolved`] when the input has none, like an example missing what
+/// the puzzle inputs have.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    Point { x: i64, y: i64 },
+    Unsolved,
+}
+
+impl Answer {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Int(_) => "int",
+            Answer::Text(_) => "text",
+            Answer::Point { .. } => "point",
+            Answer::Unsolved => "unsolved",
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(value) => write!(f, "{value}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Point { x, y } => write!(f, "{x},{y}"),
+            Answer::Unsolved => write!(f, "no answer"),
+        }
+    }
+}
+
+macro_rules! int_answer {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                Answer::Int(value as i128)
+            }
+        })*
+    };
+}
+int_answer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(value) => Answer::Int(value),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+impl From<IPoint> for Answer {
+    fn from(point: IPoint) -> Self {
+        Answer::Point {
+            x: point.x as i64,
+            y: point.y as i64,
+        }
+    }
+}
+impl From<UPoint> for Answer {
+    fn from(point: UPoint) -> Self {
+        point.signed().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::geometry::UPoint;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(306usize).to_string(), "306");
+        assert_eq!(Answer::from(-5isize).to_string(), "-5");
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("4,6,3,5").to_string(), "4,6,3,5");
+        assert_eq!(Answer::Point { x: 6, y: 1 }.to_string(), "6,1");
+        assert_eq!(
+            Answer::from(UPoint::new(6, 1)),
+            Answer::Point { x: 6, y: 1 }
+        );
+        assert_eq!(Answer::Point { x: 6, y: 1 }.kind(), "point");
+        assert_eq!(Answer::Unsolved.to_string(), "no answer");
+    }
+}
@@ -0,0 +1,211 @@
+use crate::input::InputSource;
+use crate::parse::{Line, Lines, ParseError};
+use crate::solution::Solution;
+
+/// Starts a block of an examples file: `=== name part1=<answer> part2=<answer>`, one of the
+/// answers may be left out. The block's input runs up to the next header, trailing empty lines
+/// excluded.
+pub const HEADER: &str = "=== ";
+
+/// Named example input with the answers it's expected to give.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+    fn parse_header(line: Line) -> Result<Self, ParseError> {
+        let mut words = line.strip_prefix(line.text, HEADER)?.split(' ');
+        let name = words.next().unwrap_or_default();
+        if name.is_empty() || !name.chars().all(|c| c == '_' || c.is_ascii_alphanumeric()) {
+            return Err(line.error(name, "an example name made of letters, digits and '_'"));
+        }
+        let mut example = Example {
+            name: name.to_string(),
+            input: String::new(),
+            part1: None,
+            part2: None,
+        };
+        for word in words.filter(|word| !word.is_empty()) {
+            let (expected, answer) = match word.split_once('=') {
+                Some(("part1", answer)) if !answer.is_empty() => (&mut example.part1, answer),
+                Some(("part2", answer)) if !answer.is_empty() => (&mut example.part2, answer),
+                _ => return Err(line.error(word, "part1=<answer> or part2=<answer>")),
+            };
+            *expected = Some(answer.to_string());
+        }
+        Ok(example)
+    }
+}
+
+/// Splits an examples file into its blocks.
+pub fn parse_examples(text: &str) -> Result<Vec<Example>, ParseError> {
+    let mut examples: Vec<Example> = Vec::new();
+    let mut input: Vec<&str> = Vec::new();
+    for line in Lines::new(text) {
+        if line.text.starts_with(HEADER) {
+            finish(examples.last_mut(), &mut input);
+            let example = Example::parse_header(line)?;
+            if examples.iter().any(|known| known.name == example.name) {
+                return Err(line.error(&line.text[HEADER.len()..], "a new example name"));
+            }
+            if example.part1.is_none() && example.part2.is_none() {
+                return Err(line.error(line.end(), "part1=<answer> or part2=<answer>"));
+            }
+            examples.push(example);
+        } else if !examples.is_empty() {
+            input.push(line.text);
+        } else if !line.text.trim().is_empty() {
+            return Err(line.error(line.text, "a header like \"=== name part1=<answer>\""));
+        }
+    }
+    finish(examples.last_mut(), &mut input);
+    Ok(examples)
+}
+
+/// Moves the lines collected since the header into the block, without the trailing empty ones.
+fn finish(example: Option<&mut Example>, input: &mut Vec<&str>) {
+    while input.last().is_some_and(|line| line.is_empty()) {
+        input.pop();
+    }
+    if let Some(example) = example {
+        example.input = input.drain(..).map(|line| format!("{line}\n")).collect();
+    }
+}
+
+/// Reads the examples file `name` from `resources`, panics when it's missing or malformed.
+pub fn load(resources: &str, name: &str) -> Vec<Example> {
+    let source = InputSource::resource(resources, name);
+    let text = source.read_to_string().unwrap_or_else(|e| panic!("{e}"));
+    parse_examples(&text).unwrap_or_else(|e| panic!("{source}: {e}"))
+}
+
+/// Solves the example `name` with `S`, panics when an answer differs from the expected one.
+pub fn check<S: Solution>(examples: &[Example], name: &str) {
+    let example = examples
+        .iter()
+        .find(|example| example.name == name)
+        .unwrap_or_else(|| panic!("no example named {name}"));
+    let parsed = S::parse(&example.input).unwrap_or_else(|e| panic!("example {name}: {e}"));
+    for (part, solve) in [(1, S::part1 as fn(&S::Parsed) -> _), (2, S::part2)] {
+        if let Some(expected) = example.expected(part) {
+            assert_eq!(
+                solve(&parsed).to_string(),
+                expected,
+                "example {name}, part {part}"
+            );
+        }
+    }
+}
+
+/// Generates a `#[test]` per listed block of an examples file in the calling crate's resources,
+/// solved with the given [`Solution`]. Another test makes sure no block of the file is left out.
+/// ```ignore
+/// aoc_common::example_tests!(Day15, "examples.txt", [small, larger]);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty, $file:literal, [$($name:ident),+ $(,)?]) => {
+        $(
+            #[test]
+            fn $name() {
+                let examples = $crate::examples::load($crate::resources_dir!(), $file);
+                $crate::examples::check::<$solution>(&examples, stringify!($name));
+            }
+        )+
+        #[test]
+        fn every_example_is_tested() {
+            let examples = $crate::examples::load($crate::resources_dir!(), $file);
+            let names: Vec<&str> = examples.iter().map(|example| example.name.as_str()).collect();
+            assert_eq!(names, [$(stringify!($name)),+], "blocks of {}", $file);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{Example, check, parse_examples};
+    use crate::parse::{ParseError, parse_lines};
+    use crate::{Answer, Solution};
+
+    struct Sum;
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const RESOURCES: &'static str = "";
+        type Parsed = Vec<u32>;
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            parse_lines(input, |line| line.parse(line.text, "a number"))
+        }
+        fn part1(numbers: &Self::Parsed) -> Answer {
+            numbers.iter().sum::<u32>().into()
+        }
+        fn part2(numbers: &Self::Parsed) -> Answer {
+            numbers.iter().max().copied().unwrap_or_default().into()
+        }
+    }
+
+    #[test]
+    fn test_parse_examples() {
+        let examples =
+            parse_examples("\n=== first part1=3\n1\n2\n\n=== second part2=5 part1=9\n4\n5\n")
+                .unwrap();
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    name: "first".to_string(),
+                    input: "1\n2\n".to_string(),
+                    part1: Some("3".to_string()),
+                    part2: None,
+                },
+                Example {
+                    name: "second".to_string(),
+                    input: "4\n5\n".to_string(),
+                    part1: Some("9".to_string()),
+                    part2: Some("5".to_string()),
+                },
+            ]
+        );
+        check::<Sum>(&examples, "first");
+        check::<Sum>(&examples, "second");
+    }
+    #[test]
+    #[should_panic(expected = "example first, part 1")]
+    fn test_check_mismatch() {
+        check::<Sum>(&parse_examples("=== first part1=4\n1\n2").unwrap(), "first");
+    }
+    #[test]
+    fn test_parse_errors() {
+        let error = |text| parse_examples(text).unwrap_err().to_string();
+        assert_eq!(
+            error("1\n=== a part1=1"),
+            "line 1, column 1: expected a header like \"=== name part1=<answer>\", found \"1\""
+        );
+        assert_eq!(
+            error("=== a-b part1=1"),
+            "line 1, column 5: expected an example name made of letters, digits and '_', found \"a-b\""
+        );
+        assert_eq!(
+            error("=== a part3=1"),
+            "line 1, column 7: expected part1=<answer> or part2=<answer>, found \"part3=1\""
+        );
+        assert_eq!(
+            error("=== a\n1"),
+            "line 1, column 6: expected part1=<answer> or part2=<answer>, found end of line"
+        );
+        assert_eq!(
+            error("=== a part1=1\n=== a part2=1"),
+            "line 2, column 5: expected a new example name, found \"a part2=1\""
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Signed point, also used for offsets between points.
+pub type IPoint = Point<isize>;
+/// Unsigned point, e.g. a cell of a grid.
+pub type UPoint = Point<usize>;
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl IPoint {
+    pub fn manhattan(self, other: IPoint) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+    pub fn rotate_clockwise(self) -> IPoint {
+        IPoint::new(-self.y, self.x)
+    }
+    pub fn rotate_counter_clockwise(self) -> IPoint {
+        IPoint::new(self.y, -self.x)
+    }
+    /// The same point when both coordinates are non-negative.
+    pub fn unsigned(self) -> Option<UPoint> {
+        Some(UPoint::new(
+            usize::try_from(self.x).ok()?,
+            usize::try_from(self.y).ok()?,
+        ))
+    }
+}
+
+impl UPoint {
+    pub fn manhattan(self, other: UPoint) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+    pub fn signed(self) -> IPoint {
+        IPoint::new(self.x as isize, self.y as isize)
+    }
+    /// Point moved by `offset`, `None` when a coordinate would become negative.
+    pub fn checked_offset(self, offset: impl Into<IPoint>) -> Option<UPoint> {
+        let offset = offset.into();
+        Some(UPoint::new(
+            self.x.checked_add_signed(offset.x)?,
+            self.y.checked_add_signed(offset.y)?,
+        ))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Directions on the screen, `y` grows downwards so `Up` is `(0, -1)`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting with `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn rotate_clockwise(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+    pub fn rotate_counter_clockwise(self) -> Direction {
+        self.rotate_clockwise().opposite()
+    }
+    pub fn opposite(self) -> Direction {
+        self.rotate_clockwise().rotate_clockwise()
+    }
+    pub fn offset(self) -> IPoint {
+        match self {
+            Direction::Up => IPoint::new(0, -1),
+            Direction::Right => IPoint::new(1, 0),
+            Direction::Down => IPoint::new(0, 1),
+            Direction::Left => IPoint::new(-1, 0),
+        }
+    }
+    /// Parses the `^>v<` arrows.
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let arrow = match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        };
+        write!(f, "{arrow}")
+    }
+}
+
+/// Compass directions, `North` is up on the screen.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass {
+    /// Clockwise, starting with `North`.
+    pub const ALL: [Compass; 8] = [
+        Compass::North,
+        Compass::NorthEast,
+        Compass::East,
+        Compass::SouthEast,
+        Compass::South,
+        Compass::SouthWest,
+        Compass::West,
+        Compass::NorthWest,
+    ];
+
+    /// Rotates by 45 degrees.
+    pub fn rotate_clockwise(self) -> Compass {
+        Self::ALL[(self as usize + 1) % Self::ALL.len()]
+    }
+    /// Rotates by 45 degrees.
+    pub fn rotate_counter_clockwise(self) -> Compass {
+        Self::ALL[(self as usize + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+    pub fn offset(self) -> IPoint {
+        match self {
+            Compass::North => IPoint::new(0, -1),
+            Compass::NorthEast => IPoint::new(1, -1),
+            Compass::East => IPoint::new(1, 0),
+            Compass::SouthEast => IPoint::new(1, 1),
+            Compass::South => IPoint::new(0, 1),
+            Compass::SouthWest => IPoint::new(-1, 1),
+            Compass::West => IPoint::new(-1, 0),
+            Compass::NorthWest => IPoint::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Compass::North,
+            Direction::Right => Compass::East,
+            Direction::Down => Compass::South,
+            Direction::Left => Compass::West,
+        }
+    }
+}
+impl From<Direction> for IPoint {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+impl From<Compass> for IPoint {
+    fn from(compass: Compass) -> Self {
+        compass.offset()
+    }
+}
+impl Add<Direction> for IPoint {
+    type Output = IPoint;
+    fn add(self, rhs: Direction) -> IPoint {
+        self + rhs.offset()
+    }
+}
+impl AddAssign<Direction> for IPoint {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self += rhs.offset()
+    }
+}
+impl Add<Compass> for IPoint {
+    type Output = IPoint;
+    fn add(self, rhs: Compass) -> IPoint {
+        self + rhs.offset()
+    }
+}
+/// Panics when stepping to a negative coordinate, use [`UPoint::checked_offset`] near the edges.
+impl Add<Direction> for UPoint {
+    type Output = UPoint;
+    fn add(self, rhs: Direction) -> UPoint {
+        match self.checked_offset(rhs) {
+            Some(point) => point,
+            None => panic!("moving {rhs:?} from {self} leaves the unsigned plane"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Compass, Direction, IPoint, UPoint};
+
+    #[test]
+    fn test_points() {
+        let point = IPoint::new(3, -4);
+        assert_eq!(point + IPoint::new(1, 1), IPoint::new(4, -3));
+        assert_eq!(point - IPoint::new(1, 1), IPoint::new(2, -5));
+        assert_eq!(-point * 2, IPoint::new(-6, 8));
+        assert_eq!(point.manhattan(IPoint::default()), 7);
+        assert_eq!(point.unsigned(), None);
+        assert_eq!(point.to_string(), "3,-4");
+
+        let cell = UPoint::new(1, 0);
+        assert_eq!(
+            cell.checked_offset(Direction::Left),
+            Some(UPoint::new(0, 0))
+        );
+        assert_eq!(cell.checked_offset(Direction::Up), None);
+        assert_eq!(cell + Direction::Down, UPoint::new(1, 1));
+        assert_eq!(cell.signed().unsigned(), Some(cell));
+    }
+    #[test]
+    fn test_rotations() {
+        let direction = Direction::Down;
+        assert_eq!(direction.rotate_clockwise(), Direction::Left);
+        assert_eq!(direction.rotate_counter_clockwise(), Direction::Right);
+        assert_eq!(direction.opposite(), Direction::Up);
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset().rotate_clockwise(),
+                direction.rotate_clockwise().offset()
+            );
+            assert_eq!(
+                direction.offset().rotate_counter_clockwise(),
+                direction.rotate_counter_clockwise().offset()
+            );
+            assert_eq!(
+                Direction::from_arrow(direction.to_string().chars().next().unwrap()),
+                Some(direction)
+            );
+        }
+        assert_eq!(Compass::NorthWest.rotate_clockwise(), Compass::North);
+        assert_eq!(
+            Compass::North.rotate_counter_clockwise(),
+            Compass::NorthWest
+        );
+        assert_eq!(
+            Compass::from(Direction::Left).offset(),
+            Direction::Left.offset()
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{Compass, Direction, IPoint, UPoint};
+use crate::parse::{Lines, ParseError};
+
+/// Position of a cell, `x` being the column and `y` the row.
+pub type Cell = UPoint;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+    /// Builds a grid from rows of equal length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Self {
+        let mut grid = Self {
+            width: 0,
+            height: 0,
+            cells: Vec::new(),
+        };
+        for row in rows {
+            if grid.height == 0 {
+                grid.width = row.len();
+            }
+            assert_eq!(
+                row.len(),
+                grid.width,
+                "row {} has a different length",
+                grid.height
+            );
+            grid.cells.extend(row);
+            grid.height += 1;
+        }
+        grid
+    }
+    /// Reads lines up to the first empty one or the end of input, mapping every char to a cell.
+    /// `cell` returns `None` for chars that aren't `expected`.
+    pub fn parse(
+        lines: &mut Lines,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Self, ParseError> {
+        let first = lines.number() + 1;
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for line in lines.section() {
+            let row = line
+                .text
+                .chars()
+                .enumerate()
+                .map(|(idx, char)| cell(char).ok_or_else(|| line.error_at_char(idx, expected)))
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(width) = rows.first().map(Vec::len)
+                && row.len() != width
+            {
+                return Err(
+                    line.error_at_char(width.min(row.len()), format!("a row of {width} cells"))
+                );
+            }
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            return Err(ParseError::new(first, 1, expected, ""));
+        }
+        Ok(Self::from_rows(rows))
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn contains(&self, cell: Cell) -> bool {
+        cell.x < self.width && cell.y < self.height
+    }
+    fn index_of(&self, cell: Cell) -> Option<usize> {
+        self.contains(cell).then_some(cell.y * self.width + cell.x)
+    }
+    pub fn get(&self, cell: Cell) -> Option<&T> {
+        self.index_of(cell).map(|idx| &self.cells[idx])
+    }
+    pub fn get_mut(&mut self, cell: Cell) -> Option<&mut T> {
+        self.index_of(cell).map(|idx| &mut self.cells[idx])
+    }
+    /// Replaces the value of a cell and returns the old one, `None` when the cell is outside.
+    pub fn set(&mut self, cell: Cell, value: T) -> Option<T> {
+        self.get_mut(cell).map(|old| std::mem::replace(old, value))
+    }
+    /// Cell moved by `offset`, when it's still inside the grid.
+    pub fn offset(&self, cell: Cell, offset: impl Into<IPoint>) -> Option<Cell> {
+        let cell = cell.checked_offset(offset)?;
+        self.contains(cell).then_some(cell)
+    }
+    /// Cells sharing an edge, clockwise from the one above.
+    pub fn neighbours4(&self, cell: Cell) -> impl Iterator<Item = Cell> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(cell, direction))
+    }
+    /// Cells sharing an edge or a corner, clockwise from the one above.
+    pub fn neighbours8(&self, cell: Cell) -> impl Iterator<Item = Cell> + '_ {
+        Compass::ALL
+            .into_iter()
+            .filter_map(move |compass| self.offset(cell, compass))
+    }
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "row {y} outside of grid with height {}",
+            self.height
+        );
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} outside of grid with width {}",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+    pub fn cells(&self) -> impl Iterator<Item = Cell> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Cell::new(x, y)))
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (Cell, &T)> {
+        self.cells().zip(self.cells.iter())
+    }
+    /// First cell, row by row, whose value matches.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Cell> {
+        self.iter()
+            .find_map(|(cell, value)| predicate(value).then_some(cell))
+    }
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Cell> for Grid<T> {
+    type Output = T;
+    fn index(&self, cell: Cell) -> &T {
+        match self.get(cell) {
+            Some(value) => value,
+            None => panic!("{cell} outside of {}x{} grid", self.width, self.height),
+        }
+    }
+}
+impl<T> IndexMut<Cell> for Grid<T> {
+    fn index_mut(&mut self, cell: Cell) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(cell) {
+            Some(value) => value,
+            None => panic!("{cell} outside of {width}x{height} grid"),
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{value}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::{Compass, IPoint, UPoint};
+    use crate::grid::Grid;
+    use crate::parse::{Lines, ParseError};
+
+    fn example() -> Grid<char> {
+        Grid::parse(&mut Lines::new("abc\ndef\n\nrest"), Some, "a letter").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert_eq!(grid.get(UPoint::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(UPoint::new(3, 0)), None);
+        assert_eq!(grid.position(|&c| c == 'e'), Some(UPoint::new(1, 1)));
+    }
+    #[test]
+    fn test_parse_errors() {
+        let letter = |c: char| c.is_alphabetic().then_some(c);
+        assert_eq!(
+            Grid::parse(&mut Lines::new("abc\nd1f"), letter, "a letter"),
+            Err(ParseError::new(2, 2, "a letter", '1'))
+        );
+        assert_eq!(
+            Grid::parse(&mut Lines::new("abc\nde"), letter, "a letter"),
+            Err(ParseError::new(2, 3, "a row of 3 cells", ""))
+        );
+        assert!(Grid::parse(&mut Lines::new(""), letter, "a letter").is_err());
+    }
+    #[test]
+    fn test_views() {
+        let mut grid = example();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.set(UPoint::new(0, 0), 'x'), Some('a'));
+        assert_eq!(grid.set(UPoint::new(0, 2), 'x'), None);
+        assert_eq!(grid[UPoint::new(0, 0)], 'x');
+        assert_eq!(
+            grid.map(|&c| if c == 'x' { '#' } else { '.' }).to_string(),
+            "#..\n...\n"
+        );
+    }
+    #[test]
+    fn test_neighbours() {
+        let grid = example();
+        assert_eq!(
+            grid.neighbours4(UPoint::new(0, 0)).collect::<Vec<_>>(),
+            vec![UPoint::new(1, 0), UPoint::new(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8(UPoint::new(1, 0)).collect::<Vec<_>>(),
+            vec![
+                UPoint::new(2, 0),
+                UPoint::new(2, 1),
+                UPoint::new(1, 1),
+                UPoint::new(0, 1),
+                UPoint::new(0, 0)
+            ]
+        );
+        assert_eq!(
+            grid.offset(UPoint::new(1, 1), Compass::NorthEast),
+            Some(UPoint::new(2, 0))
+        );
+        assert_eq!(grid.offset(UPoint::new(1, 1), IPoint::new(-2, 0)), None);
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+pub type Input = Box<dyn BufRead>;
+
+/// Environment variable overriding the puzzle input path, `-` reads from stdin.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn resource(resources: impl AsRef<Path>, name: &str) -> Self {
+        Self::Path(resources.as_ref().join(name))
+    }
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::Path(PathBuf::from(path)),
+        }
+    }
+    /// An explicit `arg` wins over `AOC_INPUT`, which wins over `default`.
+    pub fn from_env_or(arg: Option<&str>, default: InputSource) -> Self {
+        Self::explicit(arg).unwrap_or(default)
+    }
+    /// `arg` or `AOC_INPUT`, `None` when the input wasn't chosen by the user.
+    pub fn explicit(arg: Option<&str>) -> Option<Self> {
+        Self::select(arg.map(str::to_string), std::env::var(INPUT_ENV).ok())
+    }
+    fn select(arg: Option<String>, env: Option<String>) -> Option<Self> {
+        match arg.or(env) {
+            Some(arg) if !arg.is_empty() => Some(Self::from_arg(&arg)),
+            _ => None,
+        }
+    }
+    pub fn open(&self) -> io::Result<Input> {
+        match self {
+            Self::Stdin => Ok(Box::new(BufReader::new(io::stdin()))),
+            Self::Path(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(e) => Err(io::Error::new(e.kind(), format!("{}: {e}", path.display()))),
+            },
+        }
+    }
+    pub fn read_to_string(&self) -> io::Result<String> {
+        read_to_string(self.open()?)
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+pub fn read_to_string(mut input: Input) -> io::Result<String> {
+    let mut buffer = String::new();
+    input.read_to_string(&mut buffer)?;
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::{InputSource, read_to_string};
+    use std::io::ErrorKind;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_select() {
+        let default = InputSource::resource("resources", "puzzle-input.txt");
+        assert_eq!(
+            InputSource::select(None, None).unwrap_or(default.clone()),
+            InputSource::Path(PathBuf::from("resources/puzzle-input.txt"))
+        );
+        assert_eq!(
+            InputSource::select(None, Some("env.txt".to_string())),
+            Some(InputSource::Path(PathBuf::from("env.txt")))
+        );
+        assert_eq!(
+            InputSource::select(Some("arg.txt".to_string()), Some("env.txt".to_string())),
+            Some(InputSource::Path(PathBuf::from("arg.txt")))
+        );
+        assert_eq!(
+            InputSource::select(Some("-".to_string()), None),
+            Some(InputSource::Stdin)
+        );
+        assert_eq!(InputSource::select(None, Some("".to_string())), None);
+    }
+    #[test]
+    fn test_open() {
+        let input = InputSource::resource(env!("CARGO_MANIFEST_DIR"), "Cargo.toml")
+            .open()
+            .unwrap();
+        assert!(read_to_string(input).unwrap().contains("aoc-common"));
+        assert_eq!(InputSource::Stdin.to_string(), "<stdin>");
+        let missing = crate::read_input!("missing.txt").err().unwrap();
+        assert_eq!(missing.kind(), ErrorKind::NotFound);
+        assert!(missing.to_string().contains("missing.txt"));
+    }
+}
@@ -0,0 +1,43 @@
+pub mod answer;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+#[cfg(feature = "reference")]
+pub mod reference;
+pub mod search;
+pub mod solution;
+pub mod visualize;
+
+pub use answer::Answer;
+pub use grid::Grid;
+pub use parse::ParseError;
+pub use solution::{DynSolution, Solution};
+
+/// Path of the calling crate's `resources` directory.
+#[macro_export]
+macro_rules! resources_dir {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/resources")
+    };
+}
+
+/// Opens `resources/{name}` of the calling crate.
+#[macro_export]
+macro_rules! read_input {
+    ($name:expr) => {
+        $crate::input::InputSource::resource($crate::resources_dir!(), $name).open()
+    };
+}
+
+/// Reads `resources/{name}` of the calling crate and parses it with `FromStr`, panics when the
+/// file can't be read.
+#[macro_export]
+macro_rules! parse_input {
+    ($name:expr) => {
+        $crate::input::read_to_string($crate::read_input!($name).unwrap())
+            .unwrap()
+            .parse()
+    };
+}
@@ -0,0 +1,271 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Position in the input, both 1-based, and what the parser was looking for there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    /// `None` at the end of the input.
+    pub found: Option<String>,
+    /// Further errors of the same input, see [`ParseError::combine`].
+    pub more: Vec<ParseError>,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Display,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+            found: Some(found.to_string()),
+            more: Vec::new(),
+        }
+    }
+    pub fn end_of_input(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            expected: expected.into(),
+            found: None,
+            more: Vec::new(),
+        }
+    }
+    /// The first of `errors` listing the others, `None` without errors.
+    pub fn combine(errors: impl IntoIterator<Item = ParseError>) -> Option<Self> {
+        let mut errors = errors.into_iter();
+        let mut first = errors.next()?;
+        first.more.extend(errors);
+        Some(first)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        match &self.found {
+            Some(found) if found.is_empty() => write!(f, "found end of line")?,
+            Some(found) => write!(f, "found {found:?}")?,
+            None => write!(f, "found end of input")?,
+        }
+        self.more
+            .iter()
+            .try_for_each(|error| write!(f, "\n{error}"))
+    }
+}
+
+impl Error for ParseError {}
+
+/// What parsers accepting malformed lines do with them, chosen by the runner.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Strictness {
+    /// Fails, listing every malformed line.
+    #[default]
+    Strict,
+    /// Skips malformed lines, logging a warning for each.
+    Lenient,
+}
+
+impl FromStr for Strictness {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "strict" => Ok(Self::Strict),
+            "lenient" => Ok(Self::Lenient),
+            _ => Err(format!("expected strict or lenient, found {s:?}")),
+        }
+    }
+}
+
+impl Display for Strictness {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Strict => write!(f, "strict"),
+            Self::Lenient => write!(f, "lenient"),
+        }
+    }
+}
+
+/// Line of the input together with its 1-based number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// 1-based column where `token`, a slice of this line, starts.
+    pub fn column(&self, token: &str) -> usize {
+        let offset = token
+            .as_ptr()
+            .addr()
+            .wrapping_sub(self.text.as_ptr().addr());
+        assert!(
+            offset <= self.text.len(),
+            "{token:?} isn't part of line {self:?}"
+        );
+        self.text[..offset].chars().count() + 1
+    }
+    /// Empty slice at the end of the line, to report missing tokens.
+    pub fn end(&self) -> &'a str {
+        &self.text[self.text.len()..]
+    }
+    /// Error pointing at `token`, a slice of this line.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column(token), expected, token)
+    }
+    /// Error pointing at the `index`th char of the line.
+    pub fn error_at_char(&self, index: usize, expected: impl Into<String>) -> ParseError {
+        let found = self
+            .text
+            .chars()
+            .nth(index)
+            .map(String::from)
+            .unwrap_or_default();
+        ParseError::new(self.number, index + 1, expected, found)
+    }
+    /// Parses `token`, a slice of this line, reporting `expected` when it fails.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+    /// Splits around the first `delimiter`, reporting `expected` when it's missing.
+    pub fn split_once(
+        &self,
+        delimiter: &str,
+        expected: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        self.text
+            .split_once(delimiter)
+            .ok_or_else(|| self.error(self.end(), expected))
+    }
+    /// Strips `prefix` from `token`, a slice of this line.
+    pub fn strip_prefix<'t>(&self, token: &'t str, prefix: &str) -> Result<&'t str, ParseError> {
+        token
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(token, format!("{prefix:?}")))
+    }
+}
+
+/// Lines of an input, keeping track of where the parser is for error reporting.
+#[derive(Debug, Clone)]
+pub struct Lines<'a> {
+    lines: std::str::Lines<'a>,
+    number: usize,
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            lines: input.lines(),
+            number: 0,
+        }
+    }
+    /// Number of the last returned line, 0 before the first one.
+    pub fn number(&self) -> usize {
+        self.number
+    }
+    /// Next line, or an error saying what was `expected` at the end of the input.
+    pub fn expect(&mut self, expected: &str) -> Result<Line<'a>, ParseError> {
+        self.next()
+            .ok_or_else(|| ParseError::end_of_input(self.number + 1, 1, expected))
+    }
+    /// Lines up to the next empty one, which is consumed, or the end of the input.
+    pub fn section(&mut self) -> impl Iterator<Item = Line<'a>> + '_ {
+        self.map_while(|line| (!line.text.is_empty()).then_some(line))
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+    fn next(&mut self) -> Option<Line<'a>> {
+        let text = self.lines.next()?;
+        self.number += 1;
+        Some(Line {
+            number: self.number,
+            text,
+        })
+    }
+}
+
+/// Parses every non-empty line with `parse`.
+pub fn parse_lines<T>(
+    input: &str,
+    parse: impl FnMut(Line) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    Lines::new(input)
+        .filter(|line| !line.text.trim().is_empty())
+        .map(parse)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{Lines, ParseError, Strictness, parse_lines};
+
+    #[test]
+    fn test_line_errors() {
+        let mut lines = Lines::new("1 2\n3 x4\n\nrest");
+        assert_eq!(lines.expect("numbers").unwrap().text, "1 2");
+        let line = lines.expect("numbers").unwrap();
+        let (_, second) = line.split_once(" ", "a space").unwrap();
+        let error = line.parse::<u32>(second, "a number").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 3, "a number", "x4"));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a number, found \"x4\""
+        );
+        assert_eq!(line.strip_prefix(second, "x"), Ok("4"));
+        assert_eq!(lines.section().count(), 0);
+        assert_eq!(
+            lines.section().map(|line| line.number).collect::<Vec<_>>(),
+            vec![4]
+        );
+        assert_eq!(
+            lines.expect("more").unwrap_err().to_string(),
+            "line 5, column 1: expected more, found end of input"
+        );
+    }
+    #[test]
+    fn test_parse_lines() {
+        let parsed = parse_lines("1\n2\n\n", |line| line.parse::<u8>(line.text, "a digit"));
+        assert_eq!(parsed, Ok(vec![1, 2]));
+        let error = parse_lines("1,\nä2", |line| {
+            let (digit, _) = line.split_once(",", "a comma")?;
+            line.parse::<u8>(digit, "a digit")
+        });
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "line 2, column 3: expected a comma, found end of line"
+        );
+    }
+    #[test]
+    fn test_combine() {
+        assert_eq!(ParseError::combine([]), None);
+        let errors = [
+            ParseError::new(1, 2, "a digit", "x"),
+            ParseError::end_of_input(3, 1, "a line"),
+        ];
+        assert_eq!(
+            ParseError::combine(errors).unwrap().to_string(),
+            "line 1, column 2: expected a digit, found \"x\"\n\
+             line 3, column 1: expected a line, found end of input"
+        );
+    }
+    #[test]
+    fn test_strictness() {
+        assert_eq!("lenient".parse(), Ok(Strictness::Lenient));
+        assert_eq!(Strictness::default().to_string(), "strict");
+        assert!("loose".parse::<Strictness>().is_err());
+    }
+}
@@ -0,0 +1,157 @@
+use crate::answer::Answer;
+use crate::solution::Solution;
+use proptest::strategy::BoxedStrategy;
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+/// Slow but obviously correct solver of a day, checked against the fast one on generated inputs.
+pub trait Reference: Solution {
+    /// Puzzle inputs small enough for the reference solver.
+    fn inputs() -> BoxedStrategy<String>;
+    /// `None` when the part can't be brute forced, even on small inputs.
+    fn reference_part1(parsed: &Self::Parsed) -> Option<Answer>;
+    fn reference_part2(parsed: &Self::Parsed) -> Option<Answer>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerifyError {
+    /// Smallest input found where the solvers disagree, with what went wrong.
+    Disagreement { input: String, reason: String },
+    /// Generating inputs failed, e.g. too many were filtered out.
+    Aborted(String),
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VerifyError::Disagreement { input, reason } => {
+                write!(f, "{reason}, smallest input:\n{input}")
+            }
+            VerifyError::Aborted(reason) => write!(f, "no inputs generated: {reason}"),
+        }
+    }
+}
+
+impl Error for VerifyError {}
+
+/// Compares both solvers of `R` on `cases` generated inputs, shrinking the first disagreement.
+pub fn verify<R: Reference>(cases: u32) -> Result<(), VerifyError> {
+    let mut runner = TestRunner::new(Config {
+        cases,
+        failure_persistence: None,
+        ..Config::default()
+    });
+    runner
+        .run(&R::inputs(), |input| {
+            compare::<R>(&input).map_err(TestCaseError::fail)
+        })
+        .map_err(|e| match e {
+            TestError::Fail(reason, input) => VerifyError::Disagreement {
+                input,
+                reason: reason.to_string(),
+            },
+            TestError::Abort(reason) => VerifyError::Aborted(reason.to_string()),
+        })
+}
+
+fn compare<R: Reference>(input: &str) -> Result<(), String> {
+    let parsed = R::parse(input).map_err(|e| format!("generated input doesn't parse: {e}"))?;
+    let parts = [
+        (
+            1,
+            R::part1 as fn(&R::Parsed) -> Answer,
+            R::reference_part1 as fn(&_) -> _,
+        ),
+        (2, R::part2, R::reference_part2),
+    ];
+    for (part, solve, reference) in parts {
+        let Some(expected) = reference(&parsed) else {
+            continue;
+        };
+        let answer = catch_unwind(AssertUnwindSafe(|| solve(&parsed))).map_err(|panic| {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            format!("part {part}: solver panicked: {message}")
+        })?;
+        if answer != expected {
+            return Err(format!(
+                "part {part}: solver gave {answer}, reference gave {expected}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Object safe view of a [`Reference`], like [`DynSolution`](crate::DynSolution).
+pub trait DynReference: Sync {
+    fn day(&self) -> u8;
+    fn verify(&self, cases: u32) -> Result<(), VerifyError>;
+}
+
+impl<R: Reference + Sync> DynReference for R {
+    fn day(&self) -> u8 {
+        R::DAY
+    }
+    fn verify(&self, cases: u32) -> Result<(), VerifyError> {
+        verify::<R>(cases)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{ParseError, parse_lines};
+    use crate::reference::{Reference, VerifyError, verify};
+    use crate::{Answer, Solution};
+    use proptest::prelude::*;
+
+    /// Part 2 finds the largest number, wrongly once one reaches 10.
+    struct Sum;
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const RESOURCES: &'static str = "";
+        type Parsed = Vec<u32>;
+        fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+            parse_lines(input, |line| line.parse(line.text, "a number"))
+        }
+        fn part1(numbers: &Self::Parsed) -> Answer {
+            numbers.iter().sum::<u32>().into()
+        }
+        fn part2(numbers: &Self::Parsed) -> Answer {
+            numbers
+                .iter()
+                .map(|n| n % 10)
+                .max()
+                .unwrap_or_default()
+                .into()
+        }
+    }
+    impl Reference for Sum {
+        fn inputs() -> BoxedStrategy<String> {
+            prop::collection::vec(0..100u32, 0..10)
+                .prop_map(|numbers| numbers.iter().map(|n| format!("{n}\n")).collect())
+                .boxed()
+        }
+        fn reference_part1(numbers: &Self::Parsed) -> Option<Answer> {
+            Some(numbers.iter().sum::<u32>().into())
+        }
+        fn reference_part2(numbers: &Self::Parsed) -> Option<Answer> {
+            Some(numbers.iter().max().copied().unwrap_or_default().into())
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(
+            verify::<Sum>(256),
+            Err(VerifyError::Disagreement {
+                input: "10\n".to_string(),
+                reason: "part 2: solver gave 0, reference gave 10".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,382 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Path from the start to the goal, both included, with its total cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+impl<N, C> Path<N, C> {
+    /// Number of moves, one less than the number of nodes.
+    pub fn steps(&self) -> usize {
+        self.nodes.len().saturating_sub(1)
+    }
+}
+
+/// Cheapest path to the first node matching `is_goal`.
+pub fn dijkstra<N, C, Successors>(
+    start: N,
+    successors: impl FnMut(&N) -> Successors,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    Successors: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], `heuristic` must never overestimate the remaining cost.
+pub fn astar<N, C, Successors>(
+    start: N,
+    mut successors: impl FnMut(&N) -> Successors,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    Successors: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = Nodes::new(start.clone());
+    let mut open = BinaryHeap::new();
+    open.push(Candidate {
+        estimate: heuristic(&start),
+        cost: C::default(),
+        index: 0,
+    });
+    while let Some(Candidate { cost, index, .. }) = open.pop() {
+        if cost > nodes.cost[index] {
+            continue;
+        }
+        let node = nodes.nodes[index].clone();
+        if is_goal(&node) {
+            return Some(Path {
+                nodes: nodes.path_to(index),
+                cost,
+            });
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if let Some(next_index) = nodes.improve(next, index, next_cost) {
+                open.push(Candidate {
+                    estimate: next_cost + heuristic(&nodes.nodes[next_index]),
+                    cost: next_cost,
+                    index: next_index,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// Shortest path to the first node matching `is_goal`, counting every move as one step.
+pub fn bfs<N, Successors>(
+    start: N,
+    mut successors: impl FnMut(&N) -> Successors,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    Successors: IntoIterator<Item = N>,
+{
+    let mut nodes = Nodes::new(start);
+    let mut open = VecDeque::from([0]);
+    while let Some(index) = open.pop_front() {
+        let node = nodes.nodes[index].clone();
+        if is_goal(&node) {
+            return Some(Path {
+                nodes: nodes.path_to(index),
+                cost: nodes.cost[index],
+            });
+        }
+        let cost = nodes.cost[index] + 1;
+        for next in successors(&node) {
+            if let Some(next_index) = nodes.insert(next, index, cost) {
+                open.push_back(next_index);
+            }
+        }
+    }
+    None
+}
+
+/// Every node reachable from the start with its cost and all predecessors on cheapest paths.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, C> {
+    start: N,
+    reached: HashMap<N, (C, Vec<N>)>,
+}
+
+/// Dijkstra over the whole reachable graph, keeping every optimal predecessor.
+pub fn dijkstra_all<N, C, Successors>(
+    start: N,
+    mut successors: impl FnMut(&N) -> Successors,
+) -> ShortestPaths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Add<Output = C> + Default,
+    Successors: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = Nodes::new(start.clone());
+    let mut predecessors = vec![Vec::new()];
+    let mut open = BinaryHeap::new();
+    open.push(Candidate {
+        estimate: C::default(),
+        cost: C::default(),
+        index: 0,
+    });
+    while let Some(Candidate { cost, index, .. }) = open.pop() {
+        if cost > nodes.cost[index] {
+            continue;
+        }
+        let node = nodes.nodes[index].clone();
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            match nodes.index.get(&next) {
+                Some(&next_index) if next_cost == nodes.cost[next_index] => {
+                    predecessors[next_index].push(node.clone());
+                }
+                Some(&next_index) if next_cost > nodes.cost[next_index] => {}
+                _ => {
+                    let next_index = nodes.improve(next, index, next_cost).unwrap();
+                    predecessors.resize_with(nodes.nodes.len(), Vec::new);
+                    predecessors[next_index] = vec![node.clone()];
+                    open.push(Candidate {
+                        estimate: next_cost,
+                        cost: next_cost,
+                        index: next_index,
+                    });
+                }
+            }
+        }
+    }
+    let reached = nodes
+        .nodes
+        .into_iter()
+        .zip(nodes.cost)
+        .zip(predecessors)
+        .map(|((node, cost), predecessors)| (node, (cost, predecessors)))
+        .collect();
+    ShortestPaths { start, reached }
+}
+
+impl<N, C> ShortestPaths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy,
+{
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.reached.get(node).map(|(cost, _)| *cost)
+    }
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.reached
+            .get(node)
+            .map_or(&[], |(_, predecessors)| predecessors.as_slice())
+    }
+    pub fn reached(&self) -> impl Iterator<Item = (&N, C)> {
+        self.reached.iter().map(|(node, (cost, _))| (node, *cost))
+    }
+    /// One of the cheapest paths, following the first predecessor of every node back to the
+    /// start, which has predecessors too when a cycle costs nothing.
+    pub fn path_to(&self, goal: &N) -> Option<Path<N, C>> {
+        let cost = self.cost(goal)?;
+        let mut nodes = vec![goal.clone()];
+        while nodes.last() != Some(&self.start) {
+            let Some(previous) = self.predecessors(nodes.last().unwrap()).first() else {
+                break;
+            };
+            nodes.push(previous.clone());
+        }
+        nodes.reverse();
+        debug_assert!(nodes.first() == Some(&self.start));
+        Some(Path { nodes, cost })
+    }
+    /// Nodes lying on any cheapest path to one of the goals.
+    pub fn nodes_on_paths(&self, goals: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut seen = HashSet::new();
+        let mut stack = goals
+            .into_iter()
+            .filter(|goal| self.reached.contains_key(goal))
+            .collect::<Vec<_>>();
+        while let Some(node) = stack.pop() {
+            if seen.insert(node.clone()) {
+                stack.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Nodes seen so far, with the best known cost and the parent it was reached from.
+struct Nodes<N, C> {
+    nodes: Vec<N>,
+    cost: Vec<C>,
+    parent: Vec<usize>,
+    index: HashMap<N, usize>,
+}
+
+impl<N, C> Nodes<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default,
+{
+    fn new(start: N) -> Self {
+        Self {
+            nodes: vec![start.clone()],
+            cost: vec![C::default()],
+            parent: vec![0],
+            index: HashMap::from([(start, 0)]),
+        }
+    }
+    /// Records `node` when it wasn't seen yet, returns its index.
+    fn insert(&mut self, node: N, parent: usize, cost: C) -> Option<usize> {
+        match self.index.entry(node) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                let index = self.nodes.len();
+                self.nodes.push(entry.key().clone());
+                entry.insert(index);
+                self.cost.push(cost);
+                self.parent.push(parent);
+                Some(index)
+            }
+        }
+    }
+    /// Records `node` when it's new or `cost` is cheaper than the known one, returns its index.
+    fn improve(&mut self, node: N, parent: usize, cost: C) -> Option<usize> {
+        match self.index.get(&node) {
+            Some(&index) if cost < self.cost[index] => {
+                self.cost[index] = cost;
+                self.parent[index] = parent;
+                Some(index)
+            }
+            Some(_) => None,
+            None => self.insert(node, parent, cost),
+        }
+    }
+    fn path_to(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].clone()];
+        while index != 0 {
+            index = self.parent[index];
+            path.push(self.nodes[index].clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Entry of the open set, the heap pops the lowest estimate first.
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<C: Ord> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<C: Ord> Eq for Candidate<C> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::UPoint;
+    use crate::grid::Grid;
+    use crate::parse::Lines;
+    use crate::search::{astar, bfs, dijkstra, dijkstra_all};
+
+    fn maze() -> Grid<char> {
+        Grid::parse(
+            &mut Lines::new("S..#\n.#..\n...E\n.###"),
+            Some,
+            "a maze tile",
+        )
+        .unwrap()
+    }
+    fn open_neighbours(maze: &Grid<char>, cell: UPoint) -> Vec<UPoint> {
+        maze.neighbours4(cell)
+            .filter(|&next| maze[next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let maze = maze();
+        let (start, end) = (UPoint::new(0, 0), UPoint::new(3, 2));
+        let path = bfs(
+            start,
+            |&cell| open_neighbours(&maze, cell),
+            |&cell| cell == end,
+        )
+        .unwrap();
+        assert_eq!(path.steps(), 5);
+        assert_eq!(path.nodes.first(), Some(&start));
+        assert_eq!(path.nodes.last(), Some(&end));
+        assert!(
+            path.nodes
+                .windows(2)
+                .all(|pair| pair[0].manhattan(pair[1]) == 1)
+        );
+
+        let weighted = |&cell: &UPoint| {
+            open_neighbours(&maze, cell)
+                .into_iter()
+                .map(move |next| (next, if next.y == 0 { 10 } else { 1 }))
+        };
+        let cheapest = dijkstra(start, weighted, |&cell| cell == end).unwrap();
+        assert_eq!(cheapest.cost, 5);
+        assert_eq!(cheapest.nodes[1], UPoint::new(0, 1));
+        let guided = astar(
+            start,
+            weighted,
+            |cell| cell.manhattan(end),
+            |&cell| cell == end,
+        );
+        assert_eq!(guided, Some(cheapest));
+
+        assert_eq!(bfs(start, |_| [], |&cell| cell == end), None);
+    }
+    #[test]
+    fn test_all_shortest_paths() {
+        let maze = maze();
+        let (start, end) = (UPoint::new(0, 0), UPoint::new(3, 2));
+        let paths = dijkstra_all(start, |&cell| {
+            open_neighbours(&maze, cell)
+                .into_iter()
+                .map(|next| (next, 1))
+        });
+        assert_eq!(paths.cost(&end), Some(5));
+        assert_eq!(paths.path_to(&end).unwrap().steps(), 5);
+        assert_eq!(paths.predecessors(&UPoint::new(2, 2)).len(), 2);
+        assert_eq!(paths.nodes_on_paths([end]).len(), 10);
+        assert_eq!(paths.reached().count(), 11);
+
+        // a cycle costing nothing back to the start
+        let paths = dijkstra_all(0, |&node| match node {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (2, 1)],
+            _ => vec![],
+        });
+        assert_eq!(paths.predecessors(&0), [1]);
+        assert_eq!(paths.path_to(&2).unwrap().nodes, [0, 1, 2]);
+    }
+}
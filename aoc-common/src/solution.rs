@@ -0,0 +1,97 @@
+use crate::answer::Answer;
+use crate::parse::{ParseError, Strictness};
+use std::any::Any;
+use tracing::{debug, info_span};
+
+pub trait Solution {
+    const DAY: u8;
+    /// Directory holding the day's `puzzle-input.txt`, usually `resources_dir!()`.
+    const RESOURCES: &'static str;
+    type Parsed;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError>;
+    /// Like [`Solution::parse`], for days whose parser can skip malformed lines.
+    fn parse_with(input: &str, _strictness: Strictness) -> Result<Self::Parsed, ParseError> {
+        Self::parse(input)
+    }
+    fn part1(parsed: &Self::Parsed) -> Answer;
+    fn part2(parsed: &Self::Parsed) -> Answer;
+    /// How a part reached its answer, for days that can explain it.
+    fn details(_parsed: &Self::Parsed, _part: u8) -> Option<String> {
+        None
+    }
+}
+
+/// Type erased [`Solution::Parsed`], shareable between threads.
+pub type AnyParsed = dyn Any + Send + Sync;
+
+/// Object safe view of a [`Solution`], so that days with different parsed types can share a
+/// registry. The parsed input is type erased and must be passed back to the same day.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+    fn resources(&self) -> &'static str;
+    fn parse(&self, input: &str) -> Result<Box<AnyParsed>, ParseError> {
+        self.parse_with(input, Strictness::Strict)
+    }
+    fn parse_with(&self, input: &str, strictness: Strictness)
+    -> Result<Box<AnyParsed>, ParseError>;
+    fn part1(&self, parsed: &AnyParsed) -> Answer;
+    fn part2(&self, parsed: &AnyParsed) -> Answer;
+    fn details(&self, part: u8, parsed: &AnyParsed) -> Option<String>;
+    fn part(&self, part: u8, parsed: &AnyParsed) -> Answer {
+        match part {
+            1 => self.part1(parsed),
+            2 => self.part2(parsed),
+            _ => panic!("day {} has no part {part}", self.day()),
+        }
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Parsed: Send + Sync + 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+    fn resources(&self) -> &'static str {
+        S::RESOURCES
+    }
+    fn parse_with(
+        &self,
+        input: &str,
+        strictness: Strictness,
+    ) -> Result<Box<AnyParsed>, ParseError> {
+        let _span = info_span!("parse", day = S::DAY).entered();
+        Ok(Box::new(S::parse_with(input, strictness)?))
+    }
+    fn part1(&self, parsed: &AnyParsed) -> Answer {
+        solve::<S>(1, S::part1, parsed)
+    }
+    fn part2(&self, parsed: &AnyParsed) -> Answer {
+        solve::<S>(2, S::part2, parsed)
+    }
+    fn details(&self, part: u8, parsed: &AnyParsed) -> Option<String> {
+        S::details(downcast::<S>(parsed), part)
+    }
+}
+
+fn solve<S: Solution>(part: u8, solve: fn(&S::Parsed) -> Answer, parsed: &AnyParsed) -> Answer
+where
+    S::Parsed: 'static,
+{
+    let _span = info_span!("part", day = S::DAY, part).entered();
+    let answer = solve(downcast::<S>(parsed));
+    debug!(%answer, "solved");
+    answer
+}
+
+fn downcast<S: Solution>(parsed: &AnyParsed) -> &S::Parsed
+where
+    S::Parsed: 'static,
+{
+    match parsed.downcast_ref() {
+        Some(parsed) => parsed,
+        None => panic!("parsed input doesn't belong to day {}", S::DAY),
+    }
+}
@@ -0,0 +1,325 @@
+use crate::grid::{Cell, Grid};
+use crate::parse::{Lines, ParseError};
+use crate::solution::Solution;
+use std::fmt::{Display, Formatter, Write};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Color {
+    #[default]
+    Plain,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+}
+
+impl Color {
+    const ALL: [Color; 8] = [
+        Color::Plain,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::Gray,
+    ];
+    /// Letters used for the colours of recorded frames.
+    const LETTERS: &'static str = ".rgybmck";
+
+    fn ansi(self) -> &'static str {
+        match self {
+            Color::Plain => "\x1b[0m",
+            Color::Red => "\x1b[31m",
+            Color::Green => "\x1b[32m",
+            Color::Yellow => "\x1b[33m",
+            Color::Blue => "\x1b[34m",
+            Color::Magenta => "\x1b[35m",
+            Color::Cyan => "\x1b[36m",
+            Color::Gray => "\x1b[90m",
+        }
+    }
+    fn letter(self) -> char {
+        Self::LETTERS.as_bytes()[self as usize] as char
+    }
+    fn from_letter(letter: char) -> Option<Color> {
+        Self::LETTERS.find(letter).map(|idx| Self::ALL[idx])
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Glyph {
+    pub char: char,
+    pub color: Color,
+}
+
+impl Default for Glyph {
+    fn default() -> Self {
+        Self {
+            char: ' ',
+            color: Color::Plain,
+        }
+    }
+}
+
+/// One picture of a simulation, a grid of coloured chars with a caption above it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub glyphs: Grid<Glyph>,
+    pub caption: String,
+}
+
+impl Frame {
+    /// Plain frame of the `Display` output of a simulation state, short lines are padded.
+    pub fn from_display(state: &impl Display) -> Self {
+        let text = state.to_string();
+        let width = text.lines().map(|line| line.chars().count()).max();
+        Self {
+            glyphs: Grid::from_rows(text.lines().map(|line| {
+                let mut row: Vec<Glyph> = line
+                    .chars()
+                    .map(|char| Glyph {
+                        char,
+                        color: Color::Plain,
+                    })
+                    .collect();
+                row.resize(width.unwrap_or_default(), Glyph::default());
+                row
+            })),
+            caption: String::new(),
+        }
+    }
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+    /// Cells outside the frame are ignored.
+    pub fn set(&mut self, cell: Cell, char: char, color: Color) {
+        if self.glyphs.contains(cell) {
+            self.glyphs.set(cell, Glyph { char, color });
+        }
+    }
+    /// Colours every cell showing one of `chars`.
+    pub fn paint(&mut self, chars: &str, color: Color) {
+        for cell in self.glyphs.cells() {
+            let glyph = &mut self.glyphs[cell];
+            if chars.contains(glyph.char) {
+                glyph.color = color;
+            }
+        }
+    }
+    /// Marks the cells of a path with `char`, or colours them only when `char` is `None`.
+    pub fn highlight(
+        &mut self,
+        path: impl IntoIterator<Item = Cell>,
+        char: Option<char>,
+        color: Color,
+    ) {
+        for cell in path {
+            if let Some(glyph) = self.glyphs.get_mut(cell) {
+                *glyph = Glyph {
+                    char: char.unwrap_or(glyph.char),
+                    color,
+                };
+            }
+        }
+    }
+    /// The caption and the rows with ANSI colour codes, every line ended by `newline`.
+    pub fn to_ansi(&self, newline: &str) -> String {
+        let mut ansi = format!("{}{newline}", self.caption);
+        for row in self.glyphs.rows() {
+            let mut color = Color::Plain;
+            for glyph in row {
+                if glyph.color != color {
+                    color = glyph.color;
+                    ansi.push_str(color.ansi());
+                }
+                ansi.push(glyph.char);
+            }
+            if color != Color::Plain {
+                ansi.push_str(Color::Plain.ansi());
+            }
+            ansi.push_str(newline);
+        }
+        ansi
+    }
+    /// Appends the frame in the recording format: a `frame <width> <height> <caption>` header,
+    /// the rows of chars, then the rows of colour letters.
+    pub fn record(&self, recording: &mut String) {
+        let (width, height) = (self.glyphs.width(), self.glyphs.height());
+        let _ = writeln!(recording, "frame {width} {height} {}", self.caption);
+        for row in self.glyphs.rows() {
+            recording.extend(row.iter().map(|glyph| glyph.char));
+            recording.push('\n');
+        }
+        for row in self.glyphs.rows() {
+            recording.extend(row.iter().map(|glyph| glyph.color.letter()));
+            recording.push('\n');
+        }
+    }
+    /// Reads back every frame written by [`Frame::record`].
+    pub fn parse_recording(recording: &str) -> Result<Vec<Frame>, ParseError> {
+        let mut lines = Lines::new(recording);
+        let mut frames = Vec::new();
+        while let Some(header) = lines.next() {
+            let rest = header.strip_prefix(header.text, "frame ")?;
+            let mut fields = rest.splitn(3, ' ');
+            let mut size = |expected| match fields.next() {
+                Some(field) => header.parse::<usize>(field, expected),
+                None => Err(header.error(header.end(), expected)),
+            };
+            let (width, height) = (size("a frame width")?, size("a frame height")?);
+            let caption = fields.next().unwrap_or_default().to_string();
+            let mut rows = |expected: &str| {
+                (0..height)
+                    .map(|_| {
+                        let line = lines.expect(expected)?;
+                        match line.text.chars().count() == width {
+                            true => Ok(line),
+                            false => Err(line.error(line.text, format!("{width} {expected}s"))),
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            };
+            let chars = rows("frame char")?;
+            let colors = rows("colour letter")?;
+            let glyphs = chars.into_iter().zip(colors).map(|(chars, colors)| {
+                chars
+                    .text
+                    .chars()
+                    .zip(colors.text.chars().enumerate())
+                    .map(|(char, (idx, letter))| {
+                        let color = Color::from_letter(letter)
+                            .ok_or_else(|| colors.error_at_char(idx, "a colour letter"))?;
+                        Ok(Glyph { char, color })
+                    })
+                    .collect::<Result<Vec<_>, ParseError>>()
+            });
+            frames.push(Frame {
+                glyphs: Grid::from_rows(glyphs.collect::<Result<Vec<_>, _>>()?),
+                caption,
+            });
+        }
+        Ok(frames)
+    }
+}
+
+/// Plain text, without the colours.
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.caption)?;
+        for row in self.glyphs.rows() {
+            for glyph in row {
+                write!(f, "{}", glyph.char)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A simulation that can be watched step by step.
+pub trait Visualize {
+    fn frame(&self) -> Frame;
+    /// Advances the simulation, `false` once it has finished.
+    fn step(&mut self) -> bool;
+}
+
+/// Frames played back from a recording.
+pub struct Replay {
+    frames: Vec<Frame>,
+    current: usize,
+}
+
+impl Replay {
+    pub fn new(frames: Vec<Frame>) -> Self {
+        Self { frames, current: 0 }
+    }
+}
+
+impl Visualize for Replay {
+    fn frame(&self) -> Frame {
+        self.frames
+            .get(self.current)
+            .cloned()
+            .unwrap_or_else(|| Frame {
+                glyphs: Grid::new(0, 0, Glyph::default()),
+                caption: "empty recording".to_string(),
+            })
+    }
+    fn step(&mut self) -> bool {
+        if self.current + 1 < self.frames.len() {
+            self.current += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A day whose simulation can be watched.
+pub trait Animate: Solution {
+    fn animate(parsed: Self::Parsed) -> Box<dyn Visualize>;
+}
+
+/// Object safe view of an [`Animate`], like [`DynSolution`](crate::DynSolution).
+pub trait DynAnimate: Sync {
+    fn day(&self) -> u8;
+    fn animate(&self, input: &str) -> Result<Box<dyn Visualize>, ParseError>;
+}
+
+impl<A: Animate + Sync> DynAnimate for A {
+    fn day(&self) -> u8 {
+        A::DAY
+    }
+    fn animate(&self, input: &str) -> Result<Box<dyn Visualize>, ParseError> {
+        Ok(A::animate(A::parse(input)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::UPoint;
+    use crate::visualize::{Color, Frame, Replay, Visualize};
+
+    fn example() -> Frame {
+        let mut frame = Frame::from_display(&"#..\n#\n").with_caption("step 1");
+        frame.paint("#", Color::Gray);
+        frame.highlight(
+            [UPoint::new(1, 0), UPoint::new(2, 0)],
+            Some('O'),
+            Color::Green,
+        );
+        frame
+    }
+
+    #[test]
+    fn test_frame() {
+        let frame = example();
+        assert_eq!(frame.to_string(), "step 1\n#OO\n#  \n");
+        assert_eq!(
+            frame.to_ansi("\n"),
+            "step 1\n\x1b[90m#\x1b[32mOO\x1b[0m\n\x1b[90m#\x1b[0m  \n"
+        );
+    }
+    #[test]
+    fn test_recording() {
+        let mut recording = String::new();
+        example().record(&mut recording);
+        Frame::from_display(&"ab").record(&mut recording);
+        assert!(recording.starts_with("frame 3 2 step 1\n#OO\n#  \nkgg\nk..\nframe 2 1 \n"));
+        let frames = Frame::parse_recording(&recording).unwrap();
+        assert_eq!(frames, vec![example(), Frame::from_display(&"ab")]);
+
+        let mut replay = Replay::new(frames);
+        assert!(replay.step());
+        assert_eq!(replay.frame().to_string(), "\nab\n");
+        assert!(!replay.step());
+
+        let error = Frame::parse_recording("frame 2 1 x\nab\n.z\n").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 2));
+    }
+}
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timings {
+    #[serde(with = "nanos")]
+    pub min: Duration,
+    #[serde(with = "nanos")]
+    pub median: Duration,
+    #[serde(with = "nanos")]
+    pub p95: Duration,
+}
+
+impl Timings {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        samples.sort_unstable();
+        let p95 = (samples.len() * 95).div_ceil(100) - 1;
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            p95: samples[p95],
+        }
+    }
+}
+
+/// Runs `f` `iterations` times and summarizes the elapsed times.
+pub fn measure<T>(iterations: usize, mut f: impl FnMut() -> T) -> Timings {
+    Timings::from_samples(
+        (0..iterations.max(1))
+            .map(|_| {
+                let start = Instant::now();
+                black_box(f());
+                start.elapsed()
+            })
+            .collect(),
+    )
+}
+
+/// Runs `f` in a pool of a single thread, where parallel solvers run sequentially.
+#[cfg(feature = "parallel")]
+pub fn single_threaded<T: Send>(
+    f: impl FnOnce() -> T + Send,
+) -> Result<T, rayon::ThreadPoolBuildError> {
+    Ok(rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()?
+        .install(f))
+}
+
+/// Timings of every benchmarked step, keyed like `day01/part2`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline(pub BTreeMap<String, Timings>);
+
+impl Baseline {
+    pub fn key(day: u8, step: &str) -> String {
+        format!("day{day:02}/{step}")
+    }
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(serde_json::from_str(&content)?)
+    }
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Box<dyn Error>> {
+        let path = path.as_ref();
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+    /// Relative change of the median in percent, when the step is in the baseline with a median
+    /// the timer could measure.
+    pub fn change(&self, key: &str, current: &Timings) -> Option<f64> {
+        let baseline = self.0.get(key)?.median.as_secs_f64();
+        (baseline > 0.).then(|| (current.median.as_secs_f64() - baseline) / baseline * 100.)
+    }
+}
+
+pub(crate) mod nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_nanos() as u64)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_nanos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{Baseline, Timings};
+    use std::time::Duration;
+
+    #[test]
+    fn test_timings() {
+        let samples = (1..=20).rev().map(Duration::from_millis).collect();
+        assert_eq!(
+            Timings::from_samples(samples),
+            Timings {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(11),
+                p95: Duration::from_millis(19),
+            }
+        );
+        let single = Timings::from_samples(vec![Duration::from_millis(3)]);
+        assert_eq!(single.p95, Duration::from_millis(3));
+    }
+    #[test]
+    fn test_change() {
+        let timings = |ms| Timings::from_samples(vec![Duration::from_millis(ms)]);
+        let mut baseline = Baseline::default();
+        baseline.0.insert(Baseline::key(1, "parse"), timings(100));
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(
+            json,
+            r#"{"day01/parse":{"min":100000000,"median":100000000,"p95":100000000}}"#
+        );
+        let baseline: Baseline = serde_json::from_str(&json).unwrap();
+        let change = baseline.change("day01/parse", &timings(150)).unwrap();
+        assert!((change - 50.).abs() < 1e-9);
+        assert_eq!(baseline.change("day02/parse", &timings(150)), None);
+        let mut baseline = Baseline::default();
+        baseline.0.insert(Baseline::key(1, "part1"), timings(0));
+        assert_eq!(baseline.change("day01/part1", &timings(150)), None);
+    }
+}
@@ -0,0 +1,245 @@
+use serde::Deserialize;
+use std::error::Error;
+use std::fs;
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Year of the puzzles solved in this repository.
+pub const YEAR: u16 = 2024;
+/// Name of the puzzle input in a day's resources directory.
+pub const PUZZLE_INPUT: &str = "puzzle-input.txt";
+/// Environment variable overriding the config file location.
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+
+/// Where missing puzzle inputs come from.
+pub trait FetchBackend {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, Box<dyn Error>>;
+}
+
+/// Serves `root/<year>/day<N>.txt`, the same layout as the cache. Stands in for the website in
+/// tests and on machines sharing a directory of inputs.
+#[derive(Debug, Clone)]
+pub struct DirectoryBackend {
+    pub root: PathBuf,
+}
+
+impl FetchBackend for DirectoryBackend {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+        let path = layout(&self.root, year, day);
+        fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+}
+
+/// Downloads inputs from adventofcode.com with the `curl` binary, authenticated by the session
+/// cookie. The cookie is passed on stdin so that it doesn't show up in the process list.
+#[derive(Clone)]
+pub struct CurlBackend {
+    session: String,
+}
+
+impl CurlBackend {
+    pub fn new(session: &str) -> Self {
+        Self {
+            session: session.trim().to_string(),
+        }
+    }
+}
+
+impl FetchBackend for CurlBackend {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, Box<dyn Error>> {
+        let url = format!("https://adventofcode.com/{year}/day/{day}/input");
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--header", "@-"])
+            .args(["--user-agent", "aoc-2024 input cache"])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("curl: {e}"))?;
+        if let Some(mut stdin) = curl.stdin.take() {
+            writeln!(stdin, "Cookie: session={}", self.session)?;
+        }
+        let output = curl.wait_with_output()?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("{url}: {}", stderr.trim()).into());
+        }
+        Ok(String::from_utf8(output.stdout)?)
+    }
+}
+
+/// Settings read from `AOC_CONFIG`, or `~/.config/aoc/config.toml`:
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// cache_dir = "/home/me/.cache/aoc"
+/// inputs_dir = "/shared/aoc-inputs"
+/// ```
+/// Every key is optional, `inputs_dir` takes precedence over the session.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub cache_dir: Option<PathBuf>,
+    pub inputs_dir: Option<PathBuf>,
+}
+
+impl Config {
+    /// A missing file is an empty config.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(content) => content
+                .parse()
+                .map_err(|e| format!("{}: {e}", path.display()).into()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display()).into()),
+        }
+    }
+    pub fn load_default() -> Result<Self, Box<dyn Error>> {
+        match std::env::var_os(CONFIG_ENV) {
+            Some(path) => Self::load(path),
+            None => home_dir("XDG_CONFIG_HOME", ".config").map_or(Ok(Self::default()), |dir| {
+                Self::load(dir.join("aoc/config.toml"))
+            }),
+        }
+    }
+    pub fn cache_dir(&self) -> PathBuf {
+        self.cache_dir
+            .clone()
+            .or_else(|| home_dir("XDG_CACHE_HOME", ".cache").map(|dir| dir.join("aoc")))
+            .unwrap_or_else(|| std::env::temp_dir().join("aoc-cache"))
+    }
+}
+
+impl std::str::FromStr for Config {
+    type Err = toml::de::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s)
+    }
+}
+
+/// `$xdg_var`, or `$HOME/fallback`.
+fn home_dir(xdg_var: &str, fallback: &str) -> Option<PathBuf> {
+    std::env::var_os(xdg_var)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(fallback)))
+}
+
+fn layout(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{day}.txt"))
+}
+
+/// Puzzle inputs looked up in a local cache directory, then in the day's resources, and fetched
+/// from the backend as a last resort.
+pub struct InputCache {
+    dir: PathBuf,
+    backend: Option<Box<dyn FetchBackend>>,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            backend: None,
+        }
+    }
+    pub fn with_backend(mut self, backend: impl FetchBackend + 'static) -> Self {
+        self.backend = Some(Box::new(backend));
+        self
+    }
+    pub fn from_config(config: &Config) -> Self {
+        let cache = Self::new(config.cache_dir());
+        match (&config.inputs_dir, &config.session) {
+            (Some(root), _) => cache.with_backend(DirectoryBackend { root: root.clone() }),
+            (None, Some(session)) => cache.with_backend(CurlBackend::new(session)),
+            (None, None) => cache,
+        }
+    }
+    pub fn cached_path(&self, year: u16, day: u8) -> PathBuf {
+        layout(&self.dir, year, day)
+    }
+    /// Input already on disk, without fetching.
+    pub fn lookup(&self, year: u16, day: u8, resources: impl AsRef<Path>) -> Option<PathBuf> {
+        [
+            self.cached_path(year, day),
+            resources.as_ref().join(PUZZLE_INPUT),
+        ]
+        .into_iter()
+        .find(|path| path.is_file())
+    }
+    /// Input on disk, fetched into the cache when it's missing.
+    pub fn get(
+        &self,
+        year: u16,
+        day: u8,
+        resources: impl AsRef<Path>,
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        if let Some(path) = self.lookup(year, day, &resources) {
+            return Ok(path);
+        }
+        let backend = self.backend.as_ref().ok_or_else(|| {
+            format!(
+                "no puzzle input for day {day}: put it in {} or set `session` in the config",
+                resources.as_ref().join(PUZZLE_INPUT).display()
+            )
+        })?;
+        let input = backend.fetch(year, day)?;
+        let path = self.cached_path(year, day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, input).map_err(|e| format!("{}: {e}", path.display()))?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::{Config, DirectoryBackend, InputCache};
+    use std::fs;
+
+    #[test]
+    fn test_lookup_order() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (cache_dir, resources, inputs) = (
+            tmp.path().join("cache"),
+            tmp.path().join("resources"),
+            tmp.path().join("inputs"),
+        );
+        fs::create_dir_all(inputs.join("2024")).unwrap();
+        fs::write(inputs.join("2024/day3.txt"), "fetched").unwrap();
+        let backend = DirectoryBackend { root: inputs };
+
+        let offline = InputCache::new(&cache_dir);
+        assert_eq!(offline.lookup(2024, 3, &resources), None);
+        assert!(offline.get(2024, 3, &resources).is_err());
+
+        let cache = InputCache::new(&cache_dir).with_backend(backend);
+        let path = cache.get(2024, 3, &resources).unwrap();
+        assert_eq!(path, cache_dir.join("2024/day3.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fetched");
+        assert_eq!(offline.lookup(2024, 3, &resources), Some(path));
+        assert!(cache.get(2024, 4, &resources).is_err());
+
+        fs::create_dir_all(&resources).unwrap();
+        fs::write(resources.join("puzzle-input.txt"), "local").unwrap();
+        assert_eq!(
+            offline.get(2024, 4, &resources).unwrap(),
+            resources.join("puzzle-input.txt")
+        );
+    }
+    #[test]
+    fn test_config() {
+        let config: Config = "session = \"abc\"\ncache_dir = \"/tmp/aoc\""
+            .parse()
+            .unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.cache_dir(), std::path::PathBuf::from("/tmp/aoc"));
+        assert!("sesion = \"abc\"".parse::<Config>().is_err());
+        let missing = Config::load(tempfile::tempdir().unwrap().path().join("config.toml"));
+        assert!(missing.unwrap().session.is_none());
+    }
+}
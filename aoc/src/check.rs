@@ -0,0 +1,102 @@
+use serde::Serialize;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// Known answers of a day's puzzle input, stored next to it in the resources directory.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    answers: toml::Table,
+}
+
+/// [`Verdict::Unsolved`] when the solver found no answer, a failure even when none is known.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "verdict", rename_all = "lowercase")]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+    Unsolved,
+}
+
+impl KnownAnswers {
+    /// Reads `answers.toml` from `resources`, a missing file means that no answer is known yet.
+    pub fn load(resources: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let path = resources.as_ref().join(ANSWERS_FILE);
+        match fs::read_to_string(&path) {
+            Ok(content) => content
+                .parse()
+                .map_err(|e| format!("{}: {e}", path.display()).into()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display()).into()),
+        }
+    }
+    pub fn expected(&self, part: u8) -> Option<String> {
+        self.answers
+            .get(&format!("part{part}"))
+            .map(|value| match value {
+                toml::Value::String(text) => text.clone(),
+                value => value.to_string(),
+            })
+    }
+    pub fn check(&self, part: u8, answer: &impl Display) -> Verdict {
+        match self.expected(part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+        }
+    }
+}
+
+impl std::str::FromStr for KnownAnswers {
+    type Err = toml::de::Error;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            answers: s.parse()?,
+        })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            Verdict::Unknown => write!(f, "unknown"),
+            Verdict::Unsolved => write!(f, "FAIL, unsolved"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::check::{KnownAnswers, Verdict};
+    use aoc_common::Answer;
+
+    #[test]
+    fn test_check() {
+        let known: KnownAnswers = "part1 = 306\npart2 = \"6,1\"".parse().unwrap();
+        assert_eq!(known.check(1, &Answer::from(306usize)), Verdict::Pass);
+        assert_eq!(
+            known.check(1, &Answer::from(305usize)),
+            Verdict::Fail {
+                expected: "306".to_string()
+            }
+        );
+        assert_eq!(known.check(2, &Answer::Point { x: 6, y: 1 }), Verdict::Pass);
+        assert_eq!(
+            KnownAnswers::default().check(1, &Answer::from(1u8)),
+            Verdict::Unknown
+        );
+        assert_eq!(Verdict::Unsolved.to_string(), "FAIL, unsolved");
+    }
+    #[test]
+    fn test_missing_file() {
+        let known = KnownAnswers::load(env!("CARGO_MANIFEST_DIR")).unwrap();
+        assert_eq!(known.expected(1), None);
+    }
+}
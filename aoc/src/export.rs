@@ -0,0 +1,292 @@
+use aoc_common::visualize::{Color, Frame, Glyph, Visualize};
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Colours of the exported images, indexed by [`palette_index`].
+pub const PALETTE: [[u8; 3]; 9] = [
+    [0x0f, 0x0f, 0x23],
+    [0xcc, 0xcc, 0xcc],
+    [0xe0, 0x40, 0x40],
+    [0x40, 0xc0, 0x40],
+    [0xff, 0xd7, 0x00],
+    [0x40, 0x80, 0xff],
+    [0xd0, 0x50, 0xd0],
+    [0x40, 0xd0, 0xd0],
+    [0x50, 0x50, 0x60],
+];
+
+/// Uncoloured spaces and dots are background, other uncoloured chars are drawn light grey.
+pub fn palette_index(glyph: Glyph) -> u8 {
+    match glyph.color {
+        Color::Plain if matches!(glyph.char, ' ' | '.') => 0,
+        Color::Plain => 1,
+        Color::Red => 2,
+        Color::Green => 3,
+        Color::Yellow => 4,
+        Color::Blue => 5,
+        Color::Magenta => 6,
+        Color::Cyan => 7,
+        Color::Gray => 8,
+    }
+}
+
+/// A frame drawn as squares of `scale` pixels per cell, pixels being [`PALETTE`] indices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
+
+impl Image {
+    pub fn render(frame: &Frame, scale: usize) -> Self {
+        let (width, height) = (frame.glyphs.width() * scale, frame.glyphs.height() * scale);
+        let mut pixels = Vec::with_capacity(width * height);
+        for row in frame.glyphs.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|&glyph| std::iter::repeat_n(palette_index(glyph), scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+    pub fn write_png(&self, writer: impl Write) -> Result<(), png::EncodingError> {
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(PALETTE.concat());
+        encoder.write_header()?.write_image_data(&self.pixels)
+    }
+}
+
+/// Animated GIF looping over the added images.
+pub struct Gif<W: Write> {
+    encoder: gif::Encoder<W>,
+    /// Delay between frames, in hundredths of a second.
+    delay: u16,
+}
+
+impl<W: Write> Gif<W> {
+    pub fn new(
+        writer: W,
+        width: usize,
+        height: usize,
+        delay: Duration,
+    ) -> Result<Self, Box<dyn Error>> {
+        let size = |pixels: usize| {
+            u16::try_from(pixels).map_err(|_| format!("{pixels} pixels are too many for a GIF"))
+        };
+        let mut encoder =
+            gif::Encoder::new(writer, size(width)?, size(height)?, &PALETTE.concat())?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        Ok(Self {
+            encoder,
+            delay: (delay.as_millis() / 10).clamp(1, u16::MAX.into()) as u16,
+        })
+    }
+    pub fn add(&mut self, image: &Image) -> Result<(), Box<dyn Error>> {
+        let frame = gif::Frame {
+            width: image.width as u16,
+            height: image.height as u16,
+            delay: self.delay,
+            buffer: image.pixels.as_slice().into(),
+            ..gif::Frame::default()
+        };
+        Ok(self.encoder.write_frame(&frame)?)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// The last frame as a PNG
+    Png,
+    /// Every frame in an animated GIF
+    Gif,
+    /// Every frame as a numbered PNG in a directory
+    Frames,
+}
+
+pub struct Options {
+    /// Pixels per grid cell.
+    pub scale: usize,
+    /// Keeps every `every`th frame of animations, and the last one.
+    pub every: usize,
+    /// Stops the simulation after this many frames.
+    pub max_frames: usize,
+    /// Time between the frames of a GIF.
+    pub delay: Duration,
+}
+
+enum Sink {
+    Png(PathBuf),
+    Gif(PathBuf, Option<Gif<BufWriter<File>>>),
+    Frames(PathBuf, usize),
+}
+
+impl Sink {
+    fn add(&mut self, image: &Image, delay: Duration) -> Result<(), Box<dyn Error>> {
+        match self {
+            Sink::Png(path) => image.write_png(BufWriter::new(File::create(path)?))?,
+            Sink::Gif(path, gif) => {
+                if gif.is_none() {
+                    let writer = BufWriter::new(File::create(path)?);
+                    *gif = Some(Gif::new(writer, image.width, image.height, delay)?);
+                }
+                gif.as_mut().unwrap().add(image)?;
+            }
+            Sink::Frames(dir, written) => {
+                *written += 1;
+                let path = dir.join(format!("frame-{written:05}.png"));
+                image.write_png(BufWriter::new(File::create(path)?))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs the simulation and writes it to `output` as `format`. Returns the number of images.
+pub fn export(
+    visualize: &mut dyn Visualize,
+    format: Format,
+    output: &Path,
+    options: &Options,
+) -> Result<usize, Box<dyn Error>> {
+    let mut sink = match format {
+        Format::Png => Sink::Png(output.to_path_buf()),
+        Format::Gif => Sink::Gif(output.to_path_buf(), None),
+        Format::Frames => {
+            fs::create_dir_all(output)?;
+            Sink::Frames(output.to_path_buf(), 0)
+        }
+    };
+    let every = options.every.max(1);
+    let (mut step, mut images, mut last_image) = (0, 0, None);
+    loop {
+        if format != Format::Png && step % every == 0 {
+            sink.add(
+                &Image::render(&visualize.frame(), options.scale),
+                options.delay,
+            )?;
+            (images, last_image) = (images + 1, Some(step));
+        }
+        if step + 1 >= options.max_frames || !visualize.step() {
+            break;
+        }
+        step += 1;
+    }
+    if last_image != Some(step) {
+        sink.add(
+            &Image::render(&visualize.frame(), options.scale),
+            options.delay,
+        )?;
+        images += 1;
+    }
+    Ok(images)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::{Format, Gif, Image, Options, export};
+    use aoc_common::geometry::UPoint;
+    use aoc_common::visualize::{Color, Frame, Visualize};
+    use std::time::Duration;
+
+    fn example() -> Frame {
+        let mut frame = Frame::from_display(&"#.\n.O");
+        frame.paint("#", Color::Gray);
+        frame.highlight([UPoint::new(1, 1)], None, Color::Green);
+        frame
+    }
+
+    /// Counts to `last`, showing the count in the top left cell.
+    struct Counter {
+        count: usize,
+        last: usize,
+    }
+    impl Visualize for Counter {
+        fn frame(&self) -> Frame {
+            Frame::from_display(&format!("{}.", self.count % 10))
+        }
+        fn step(&mut self) -> bool {
+            self.count += 1;
+            self.count <= self.last
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let image = Image::render(&example(), 2);
+        assert_eq!((image.width, image.height), (4, 4));
+        assert_eq!(
+            image.pixels,
+            [8, 8, 0, 0, 8, 8, 0, 0, 0, 0, 3, 3, 0, 0, 3, 3]
+        );
+    }
+    #[test]
+    fn test_png() {
+        let mut png = Vec::new();
+        Image::render(&example(), 1).write_png(&mut png).unwrap();
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(pixels, [8, 0, 0, 3]);
+        assert_eq!(
+            reader.info().palette.as_ref().unwrap()[8 * 3..9 * 3],
+            [0x50, 0x50, 0x60]
+        );
+    }
+    #[test]
+    fn test_gif() {
+        let mut gif = Vec::new();
+        let image = Image::render(&example(), 3);
+        let mut encoder = Gif::new(&mut gif, 6, 6, Duration::from_millis(100)).unwrap();
+        encoder.add(&image).unwrap();
+        encoder.add(&image).unwrap();
+        drop(encoder);
+        let mut decoder = gif::DecodeOptions::new().read_info(gif.as_slice()).unwrap();
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!((frame.width, frame.height, frame.delay), (6, 6, 10));
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+        assert!(Gif::new(Vec::new(), 70_000, 1, Duration::ZERO).is_err());
+    }
+    #[test]
+    fn test_export() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = Options {
+            scale: 1,
+            every: 3,
+            max_frames: 100,
+            delay: Duration::from_millis(50),
+        };
+        let mut counter = Counter { count: 0, last: 7 };
+        let images = export(&mut counter, Format::Frames, dir.path(), &options).unwrap();
+        // frames 0, 3, 6 and the last one, 7
+        assert_eq!(images, 4);
+        assert!(dir.path().join("frame-00004.png").exists());
+
+        let png = dir.path().join("last.png");
+        let mut counter = Counter { count: 0, last: 7 };
+        let options = Options {
+            max_frames: 3,
+            ..options
+        };
+        assert_eq!(
+            export(&mut counter, Format::Png, &png, &options).unwrap(),
+            1
+        );
+        assert_eq!(counter.count, 2);
+    }
+}
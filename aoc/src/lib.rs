@@ -0,0 +1,11 @@
+pub mod bench;
+pub mod cache;
+pub mod check;
+pub mod export;
+pub mod logging;
+pub mod play;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+#[cfg(feature = "reference")]
+pub mod verify;
@@ -0,0 +1,78 @@
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, IsTerminal};
+use std::path::Path;
+use std::sync::Mutex;
+use tracing::Subscriber;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+/// Text or JSON lines subscriber, logging closed spans too so that the phases are timed.
+pub fn subscriber<W>(level: LevelFilter, json: bool, writer: W) -> Box<dyn Subscriber + Send + Sync>
+where
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    let builder = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(writer);
+    match json {
+        true => Box::new(builder.json().finish()),
+        false => Box::new(builder.with_ansi(io::stderr().is_terminal()).finish()),
+    }
+}
+
+/// Logs to stderr, or as JSON lines to `json`, `-` being stderr.
+pub fn init(level: LevelFilter, json: Option<&Path>) -> Result<(), Box<dyn Error>> {
+    let subscriber = match json {
+        None => subscriber(level, false, io::stderr),
+        Some(path) if path == Path::new("-") => subscriber(level, true, io::stderr),
+        Some(path) => subscriber(level, true, Mutex::new(File::create(path)?)),
+    };
+    tracing::subscriber::set_global_default(subscriber)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::logging::subscriber;
+    use crate::registry;
+    use serde_json::Value;
+    use std::fs::{self, File};
+    use std::sync::Mutex;
+    use tracing::level_filters::LevelFilter;
+
+    #[test]
+    fn test_json_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("log.json");
+        let writer = Mutex::new(File::create(&path).unwrap());
+        let subscriber = subscriber(LevelFilter::DEBUG, true, writer);
+        tracing::subscriber::with_default(subscriber, || {
+            let day1 = registry::find(1).unwrap();
+            let parsed = day1.parse("3   4\n4   3\n").unwrap();
+            day1.part1(parsed.as_ref());
+        });
+        let log: Vec<Value> = fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let solved = log
+            .iter()
+            .find(|event| event["fields"]["message"] == "solved")
+            .unwrap();
+        assert_eq!(solved["level"], "DEBUG");
+        assert_eq!(solved["fields"]["answer"], "0");
+        assert_eq!(solved["spans"][0]["name"], "part");
+        assert_eq!(solved["spans"][0]["day"], 1);
+        assert_eq!(solved["spans"][0]["part"], 1);
+        let closed: Vec<_> = log
+            .iter()
+            .filter(|event| event["fields"]["message"] == "close")
+            .map(|event| event["span"]["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(closed, ["parse", "part"]);
+    }
+}
@@ -0,0 +1,502 @@
+use aoc::bench::{self, Baseline, Timings};
+use aoc::cache::{Config, InputCache, YEAR};
+use aoc::check::{KnownAnswers, Verdict};
+use aoc::export;
+use aoc::logging;
+use aoc::play;
+use aoc::registry::{self, SOLUTIONS};
+use aoc::report::{self, DayRun, Reporter};
+use aoc::scaffold;
+#[cfg(feature = "reference")]
+use aoc::verify;
+use aoc_common::input::InputSource;
+use aoc_common::parse::Strictness;
+use aoc_common::visualize::{Frame, Replay, Visualize};
+use aoc_common::{Answer, DynSolution};
+use clap::{Args, Parser, Subcommand};
+use std::error::Error;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+use tracing::info_span;
+use tracing::level_filters::LevelFilter;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Most verbose events logged: off, error, warn, info, debug or trace
+    #[arg(long, global = true, default_value_t = LevelFilter::WARN)]
+    log_level: LevelFilter,
+    /// Log JSON lines to this file instead of text to stderr, `-` for stderr
+    #[arg(long, global = true)]
+    log_json: Option<PathBuf>,
+}
+
+/// Where the frames of `play` and `export` come from.
+#[derive(Args)]
+struct Simulation {
+    /// Day to animate, see `aoc_common::visualize::Animate`
+    #[arg(long, short, required_unless_present = "replay")]
+    day: Option<u8>,
+    /// Input file, `-` reads stdin. Defaults to `AOC_INPUT`, then the cached puzzle input
+    #[arg(long, short)]
+    input: Option<String>,
+    /// Play the frames of a recording instead of a day
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    replay: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver of a day, or of every day with a puzzle input
+    Run {
+        /// Day to run, all days when omitted
+        #[arg(long, short)]
+        day: Option<u8>,
+        /// Part to run, both parts when omitted
+        #[arg(long, short, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, `-` reads stdin. Defaults to `AOC_INPUT`, then the cached puzzle input
+        #[arg(long, short, requires = "day")]
+        input: Option<String>,
+        #[arg(long, short, value_enum, default_value_t = report::Format::Text)]
+        format: report::Format,
+        /// Fail on malformed lines, or skip them with a warning when lenient
+        #[arg(long, default_value_t = Strictness::Strict)]
+        strictness: Strictness,
+        /// Explain the answers of the days that can, such as the verdict of every day 2 report
+        #[arg(long)]
+        details: bool,
+    },
+    /// Compare answers for the puzzle inputs with each day's `answers.toml`
+    Check {
+        /// Day to check, all days when omitted
+        #[arg(long, short)]
+        day: Option<u8>,
+        #[arg(long, short, value_enum, default_value_t = report::Format::Text)]
+        format: report::Format,
+        /// Fail on malformed lines, or skip them with a warning when lenient
+        #[arg(long, default_value_t = Strictness::Strict)]
+        strictness: Strictness,
+        /// Explain the answers of the days that can, such as the verdict of every day 2 report
+        #[arg(long)]
+        details: bool,
+    },
+    /// Time parsing and both parts of the puzzle inputs
+    Bench {
+        /// Day to benchmark, all days when omitted
+        #[arg(long, short)]
+        day: Option<u8>,
+        /// Runs of every step
+        #[arg(long, short = 'n', default_value_t = 10)]
+        iterations: usize,
+        /// Save the timings as a JSON baseline
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare the timings with a saved baseline
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Flag steps whose median got slower than the baseline by more than this many percent
+        #[arg(long, default_value_t = 10.)]
+        threshold: f64,
+    },
+    /// Compare solvers with slower alternatives on generated inputs
+    Verify {
+        /// Day to verify, every day with a reference solver when omitted
+        #[arg(long, short)]
+        day: Option<u8>,
+        /// Compare with the brute-force reference solvers, needs the `reference` feature
+        #[arg(long, required = true)]
+        reference: bool,
+        /// Generated inputs per day
+        #[arg(long, default_value_t = 64)]
+        cases: u32,
+    },
+    /// Watch a day's simulation frame by frame
+    Play {
+        #[command(flatten)]
+        simulation: Simulation,
+        /// Frames per second, `+` and `-` change it while playing
+        #[arg(long, default_value_t = 10., value_parser = play::parse_fps)]
+        fps: f64,
+        /// Write the frames to a file
+        #[arg(long)]
+        record: Option<PathBuf>,
+        /// Stop after this many frames
+        #[arg(long)]
+        max_frames: Option<usize>,
+    },
+    /// Render a day's simulation to PNG or an animated GIF
+    Export {
+        #[command(flatten)]
+        simulation: Simulation,
+        #[arg(long, short, value_enum, default_value_t = export::Format::Png)]
+        format: export::Format,
+        /// File to write, a directory for `--format frames`
+        #[arg(long, short)]
+        output: PathBuf,
+        /// Pixels per grid cell
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+        /// Keep every Nth frame of animations
+        #[arg(long, default_value_t = 1)]
+        every: usize,
+        /// Stop after this many frames
+        #[arg(long, default_value_t = 10_000)]
+        max_frames: usize,
+        /// Frames per second of the GIF
+        #[arg(long, default_value_t = 10., value_parser = play::parse_fps)]
+        fps: f64,
+    },
+    /// List the solved days
+    List,
+    /// Create `dayN` from the template crate and register it
+    NewDay {
+        #[arg(long, short, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+fn main() -> ExitCode {
+    match execute(Cli::parse()) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(cli: Cli) -> Result<ExitCode, Box<dyn Error>> {
+    logging::init(cli.log_level, cli.log_json.as_deref())?;
+    let inputs = || Config::load_default().map(|config| InputCache::from_config(&config));
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            strictness,
+            details,
+        } => {
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            let mut reporter = Reporter::new(format, io::stdout().lock());
+            match (day, InputSource::explicit(input.as_deref())) {
+                (Some(day), _) => {
+                    let (solution, source) = source(day, input.as_deref(), &inputs()?)?;
+                    reporter.day(run(solution, &parts, &source, strictness, details)?)?;
+                }
+                (None, Some(_)) => return Err("AOC_INPUT needs a --day".into()),
+                (None, None) => {
+                    let inputs = inputs()?;
+                    for solution in SOLUTIONS {
+                        match puzzle_input(&inputs, *solution, false)? {
+                            Some(path) => {
+                                let source = InputSource::Path(path);
+                                reporter
+                                    .day(run(*solution, &parts, &source, strictness, details)?)?
+                            }
+                            None => reporter.skipped(solution.day())?,
+                        }
+                    }
+                }
+            }
+            reporter.finish()?;
+        }
+        Command::Check {
+            day,
+            format,
+            strictness,
+            details,
+        } => return check(day, format, strictness, details, &inputs()?),
+        Command::Bench {
+            day,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        } => {
+            let baseline = baseline.map(Baseline::load).transpose()?;
+            let timings = bench(day, iterations, baseline.as_ref(), threshold, &inputs()?)?;
+            if let Some(path) = save {
+                timings.save(path)?;
+            }
+            if let Some(baseline) = baseline {
+                let regressed = timings
+                    .0
+                    .iter()
+                    .filter(|(key, t)| baseline.change(key, t).is_some_and(|c| c > threshold))
+                    .count();
+                if regressed > 0 {
+                    println!(
+                        "{regressed} step(s) slower than the baseline by more than {threshold}%"
+                    );
+                    return Ok(ExitCode::FAILURE);
+                }
+            }
+        }
+        Command::Verify { day, cases, .. } => return verify(day, cases),
+        Command::Play {
+            simulation,
+            fps,
+            record,
+            max_frames,
+        } => {
+            let options = play::Options {
+                fps,
+                record: record.as_deref(),
+                max_frames,
+            };
+            play::play(simulation.load(inputs)?.as_mut(), &options)?;
+        }
+        Command::Export {
+            simulation,
+            format,
+            output,
+            scale,
+            every,
+            max_frames,
+            fps,
+        } => {
+            let options = export::Options {
+                scale,
+                every,
+                max_frames,
+                delay: Duration::from_secs_f64(1. / fps),
+            };
+            let images =
+                export::export(simulation.load(inputs)?.as_mut(), format, &output, &options)?;
+            println!("wrote {images} image(s) to {}", output.display());
+        }
+        Command::List => {
+            for solution in SOLUTIONS {
+                println!("Day {:>2}: {}", solution.day(), solution.resources());
+            }
+        }
+        Command::NewDay { day } => {
+            let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .ok_or("the aoc crate has no workspace")?;
+            for path in scaffold::new_day(root, day)? {
+                println!("wrote {}", path.display());
+            }
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+impl Simulation {
+    fn load(
+        self,
+        inputs: impl FnOnce() -> Result<InputCache, Box<dyn Error>>,
+    ) -> Result<Box<dyn Visualize>, Box<dyn Error>> {
+        match (self.replay, self.day) {
+            (Some(path), _) => {
+                let recording = std::fs::read_to_string(&path)?;
+                let frames = Frame::parse_recording(&recording)
+                    .map_err(|e| format!("{}: {e}", path.display()))?;
+                Ok(Box::new(Replay::new(frames)))
+            }
+            (None, None) => unreachable!("clap requires a day without a recording"),
+            (None, Some(day)) => {
+                let animation =
+                    play::find(day).ok_or_else(|| format!("day {day} has no animation"))?;
+                let source = source(day, self.input.as_deref(), &inputs()?)?.1;
+                Ok(animation
+                    .animate(&source.read_to_string()?)
+                    .map_err(|e| format!("{source}: {e}"))?)
+            }
+        }
+    }
+}
+
+fn selected(day: Option<u8>) -> Result<Vec<&'static dyn DynSolution>, Box<dyn Error>> {
+    Ok(match day {
+        Some(day) => vec![registry::find(day).ok_or_else(|| format!("day {day} is not solved"))?],
+        None => SOLUTIONS.to_vec(),
+    })
+}
+
+/// Puzzle input of `solution`, only fetched when `fetch` is set.
+fn puzzle_input(
+    inputs: &InputCache,
+    solution: &dyn DynSolution,
+    fetch: bool,
+) -> Result<Option<PathBuf>, Box<dyn Error>> {
+    let (day, resources) = (solution.day(), solution.resources());
+    match fetch {
+        true => inputs.get(YEAR, day, resources).map(Some),
+        false => Ok(inputs.lookup(YEAR, day, resources)),
+    }
+}
+
+fn bench(
+    day: Option<u8>,
+    iterations: usize,
+    baseline: Option<&Baseline>,
+    threshold: f64,
+    inputs: &InputCache,
+) -> Result<Baseline, Box<dyn Error>> {
+    let fetch = day.is_some();
+    let mut timings = Baseline::default();
+    for solution in selected(day)? {
+        let day = solution.day();
+        let Some(puzzle_input) = puzzle_input(inputs, solution, fetch)? else {
+            println!("Day {day:>2}: no puzzle input, skipped");
+            continue;
+        };
+        let source = InputSource::Path(puzzle_input);
+        let data = source.read_to_string()?;
+        let parsed = solution
+            .parse(&data)
+            .map_err(|e| format!("{source}: {e}"))?;
+        let steps: [(&str, Timings); 3] = [
+            (
+                "parse",
+                bench::measure(iterations, || solution.parse(&data)),
+            ),
+            (
+                "part1",
+                bench::measure(iterations, || solution.part1(parsed.as_ref())),
+            ),
+            (
+                "part2",
+                bench::measure(iterations, || solution.part2(parsed.as_ref())),
+            ),
+        ];
+        for (step, t) in steps {
+            let key = Baseline::key(day, step);
+            let change = match baseline.and_then(|b| b.change(&key, &t)) {
+                Some(change) if change > threshold => format!("  {change:+.1}% REGRESSION"),
+                Some(change) => format!("  {change:+.1}%"),
+                None => String::new(),
+            };
+            println!(
+                "Day {day:>2} {step:<5}  min {:>10.2?}  median {:>10.2?}  p95 {:>10.2?}{change}",
+                t.min, t.median, t.p95
+            );
+            timings.0.insert(key, t);
+        }
+        #[cfg(feature = "parallel")]
+        for (part, step) in [(1, "part1"), (2, "part2")] {
+            let parallel = timings.0[&Baseline::key(day, step)];
+            let threads = rayon::current_num_threads();
+            let (answer, single) = bench::single_threaded(|| {
+                let answer = solution.part(part, parsed.as_ref());
+                let t = bench::measure(iterations, || solution.part(part, parsed.as_ref()));
+                (answer, t)
+            })?;
+            if answer != solution.part(part, parsed.as_ref()) {
+                return Err(format!("day {day} {step}: answer differs on a single thread").into());
+            }
+            println!(
+                "Day {day:>2} {step:<5}  speed-up {:>5.2}x, {threads} threads vs 1",
+                single.median.as_secs_f64() / parallel.median.as_secs_f64()
+            );
+        }
+    }
+    Ok(timings)
+}
+
+fn check(
+    day: Option<u8>,
+    format: report::Format,
+    strictness: Strictness,
+    details: bool,
+    inputs: &InputCache,
+) -> Result<ExitCode, Box<dyn Error>> {
+    let fetch = day.is_some();
+    let mut failed = false;
+    let mut reporter = Reporter::new(format, io::stdout().lock());
+    for solution in selected(day)? {
+        let Some(puzzle_input) = puzzle_input(inputs, solution, fetch)? else {
+            reporter.skipped(solution.day())?;
+            continue;
+        };
+        let known = KnownAnswers::load(solution.resources())?;
+        let source = InputSource::Path(puzzle_input);
+        let mut day = run(solution, &[1, 2], &source, strictness, details)?;
+        for part in &mut day.parts {
+            let verdict = match part.answer_type == Answer::Unsolved.kind() {
+                true => Verdict::Unsolved,
+                false => known.check(part.part, &part.answer),
+            };
+            failed |= matches!(verdict, Verdict::Fail { .. } | Verdict::Unsolved);
+            part.verdict = Some(verdict);
+        }
+        reporter.day(day)?;
+    }
+    reporter.finish()?;
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+#[cfg(feature = "reference")]
+fn verify(day: Option<u8>, cases: u32) -> Result<ExitCode, Box<dyn Error>> {
+    let references = match day {
+        Some(day) => {
+            vec![verify::find(day).ok_or_else(|| format!("day {day} has no reference solver"))?]
+        }
+        None => verify::REFERENCES.to_vec(),
+    };
+    // Solvers panicking on generated inputs are reported as disagreements
+    std::panic::set_hook(Box::new(|_| {}));
+    let mut failed = false;
+    for reference in references {
+        let day = reference.day();
+        match reference.verify(cases) {
+            Ok(()) => println!("Day {day:>2}: agrees on {cases} inputs"),
+            Err(e) => {
+                failed = true;
+                println!("Day {day:>2}: {e}");
+            }
+        }
+    }
+    let _ = std::panic::take_hook();
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+#[cfg(not(feature = "reference"))]
+fn verify(_: Option<u8>, _: u32) -> Result<ExitCode, Box<dyn Error>> {
+    Err("built without reference solvers, rebuild with `--features reference`".into())
+}
+
+/// The explicit `input` of a day, or its cached puzzle input.
+fn source(
+    day: u8,
+    input: Option<&str>,
+    inputs: &InputCache,
+) -> Result<(&'static dyn DynSolution, InputSource), Box<dyn Error>> {
+    let solution = registry::find(day).ok_or_else(|| format!("day {day} is not solved"))?;
+    let source = match InputSource::explicit(input) {
+        Some(source) => source,
+        None => InputSource::Path(inputs.get(YEAR, day, solution.resources())?),
+    };
+    Ok((solution, source))
+}
+
+fn run(
+    solution: &dyn DynSolution,
+    parts: &[u8],
+    source: &InputSource,
+    strictness: Strictness,
+    details: bool,
+) -> Result<DayRun, Box<dyn Error>> {
+    let _span = info_span!("day", day = solution.day()).entered();
+    let input = source.read_to_string()?;
+    Ok(DayRun::solve(solution, parts, &input, strictness, details)
+        .map_err(|e| format!("{source}: {e}"))?)
+}
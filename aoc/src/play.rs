@@ -0,0 +1,234 @@
+use aoc_common::visualize::{DynAnimate, Frame, Visualize};
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::fs::File;
+use std::io::{self, BufWriter, IsTerminal, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Days whose simulation can be watched, ordered by day.
+pub static ANIMATIONS: &[&dyn DynAnimate] = &[
+    &day6::Day6,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day18::Day18,
+    &day20::Day20,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn DynAnimate> {
+    ANIMATIONS
+        .iter()
+        .copied()
+        .find(|animation| animation.day() == day)
+}
+
+const MIN_FPS: f64 = 0.25;
+const MAX_FPS: f64 = 1024.;
+
+/// Parses a `--fps` argument, rejecting rates the player would clamp.
+pub fn parse_fps(arg: &str) -> Result<f64, String> {
+    let fps = arg.parse::<f64>().map_err(|e| e.to_string())?;
+    match (MIN_FPS..=MAX_FPS).contains(&fps) {
+        true => Ok(fps),
+        false => Err(format!("expected between {MIN_FPS} and {MAX_FPS}")),
+    }
+}
+
+/// Playback state, changed by the keys of the player.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Controls {
+    pub fps: f64,
+    pub paused: bool,
+    /// Set by a single step while paused, until the step is taken.
+    pub step: bool,
+    pub quit: bool,
+}
+
+impl Controls {
+    pub fn new(fps: f64) -> Self {
+        Self {
+            fps: fps.clamp(MIN_FPS, MAX_FPS),
+            paused: false,
+            step: false,
+            quit: false,
+        }
+    }
+    /// Space pauses, `n` steps a single frame, `+` and `-` double or halve the speed, `q` quits.
+    pub fn press(&mut self, key: char) {
+        match key {
+            ' ' => self.paused = !self.paused,
+            'n' => {
+                self.paused = true;
+                self.step = true;
+            }
+            '+' | '=' => self.fps = (self.fps * 2.).min(MAX_FPS),
+            '-' => self.fps = (self.fps / 2.).max(MIN_FPS),
+            'q' => self.quit = true,
+            _ => {}
+        }
+    }
+    /// Whether the next frame is due, `elapsed` telling if a frame's time has passed.
+    pub fn advance(&mut self, elapsed: bool) -> bool {
+        std::mem::take(&mut self.step) || (elapsed && !self.paused)
+    }
+    pub fn delay(&self) -> Duration {
+        Duration::from_secs_f64(1. / self.fps)
+    }
+    pub fn status(&self, frames: usize, finished: bool) -> String {
+        let state = match (finished, self.paused) {
+            (true, _) => "finished",
+            (false, true) => "paused",
+            (false, false) => "playing",
+        };
+        format!(
+            "frame {frames}, {state} at {} fps | space pause, n step, +/- speed, q quit",
+            self.fps
+        )
+    }
+}
+
+pub struct Options<'a> {
+    pub fps: f64,
+    /// File the played frames are written to, for `--replay`.
+    pub record: Option<&'a Path>,
+    /// Stops the simulation after this many frames.
+    pub max_frames: Option<usize>,
+}
+
+/// Plays the simulation in the terminal, or prints every frame when stdout isn't one. Returns
+/// the number of frames shown.
+pub fn play(visualize: &mut dyn Visualize, options: &Options) -> io::Result<usize> {
+    let mut recorder = options
+        .record
+        .map(|path| File::create(path).map(BufWriter::new))
+        .transpose()?;
+    let mut record = |frame: &Frame| match &mut recorder {
+        Some(recorder) => {
+            let mut recording = String::new();
+            frame.record(&mut recording);
+            recorder.write_all(recording.as_bytes())
+        }
+        None => Ok(()),
+    };
+    let mut frames = 1;
+    let mut frame = visualize.frame();
+    record(&frame)?;
+    let mut next = |frames: &mut usize| {
+        let more = options.max_frames.is_none_or(|max| *frames < max) && visualize.step();
+        *frames += usize::from(more);
+        more.then(|| visualize.frame())
+    };
+    if !io::stdout().is_terminal() {
+        let mut stdout = io::stdout().lock();
+        loop {
+            writeln!(stdout, "{frame}")?;
+            match next(&mut frames) {
+                Some(next) => frame = next,
+                None => break,
+            }
+            record(&frame)?;
+        }
+        return Ok(frames);
+    }
+
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode()?;
+    let _restore = Restore;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+    let mut controls = Controls::new(options.fps);
+    let (mut finished, mut dirty) = (false, true);
+    while !controls.quit {
+        if dirty {
+            queue!(stdout, MoveTo(0, 0), Clear(ClearType::All))?;
+            write!(stdout, "{}", frame.to_ansi("\r\n"))?;
+            write!(stdout, "{}", controls.status(frames, finished))?;
+            stdout.flush()?;
+        }
+        let before = controls;
+        let elapsed = !event::poll(controls.delay())?;
+        if !elapsed
+            && let Event::Key(key) = event::read()?
+            && let Some(key) = char_of(key)
+        {
+            controls.press(key);
+        }
+        dirty = controls != before;
+        if controls.advance(elapsed) && !finished {
+            match next(&mut frames) {
+                Some(next) => {
+                    frame = next;
+                    record(&frame)?;
+                }
+                None => finished = true,
+            }
+            dirty = true;
+        }
+    }
+    Ok(frames)
+}
+
+/// Escape and ctrl-c quit like `q`.
+fn char_of(key: KeyEvent) -> Option<char> {
+    match (key.kind, key.code) {
+        (KeyEventKind::Release, _) => None,
+        (_, KeyCode::Esc) => Some('q'),
+        (_, KeyCode::Char('c')) if key.modifiers.contains(KeyModifiers::CONTROL) => Some('q'),
+        (_, KeyCode::Char(char)) => Some(char),
+        _ => None,
+    }
+}
+
+/// Leaves raw mode and the alternate screen, even when playing fails.
+struct Restore;
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::play::{ANIMATIONS, Controls, parse_fps};
+    use crate::registry;
+
+    #[test]
+    fn test_animations() {
+        let days: Vec<u8> = ANIMATIONS.iter().map(|a| a.day()).collect();
+        assert!(days.is_sorted_by(|a, b| a < b));
+        assert!(days.iter().all(|&day| registry::find(day).is_some()));
+    }
+    #[test]
+    fn test_parse_fps() {
+        assert_eq!(parse_fps("0.5"), Ok(0.5));
+        assert!(parse_fps("0").is_err());
+        assert!(parse_fps("-1").is_err());
+        assert!(parse_fps("NaN").is_err());
+        assert!(parse_fps("fast").is_err());
+    }
+    #[test]
+    fn test_controls() {
+        let mut controls = Controls::new(8.);
+        assert!(controls.advance(true));
+        assert!(!controls.advance(false));
+        controls.press(' ');
+        assert!(!controls.advance(true));
+        controls.press('n');
+        assert!(controls.advance(false));
+        assert!(!controls.advance(true));
+        controls.press('+');
+        controls.press('+');
+        controls.press('-');
+        assert_eq!(controls.fps, 16.);
+        assert_eq!(
+            controls.status(3, false),
+            "frame 3, paused at 16 fps | space pause, n step, +/- speed, q quit"
+        );
+        controls.press('q');
+        assert!(controls.quit);
+    }
+}
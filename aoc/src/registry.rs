@@ -0,0 +1,49 @@
+use aoc_common::DynSolution;
+
+/// Every solved day, ordered by day.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+    &day9::Day9,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn DynSolution> {
+    SOLUTIONS
+        .iter()
+        .copied()
+        .find(|solution| solution.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::{SOLUTIONS, find};
+
+    #[test]
+    fn test_registry() {
+        let days: Vec<u8> = SOLUTIONS.iter().map(|s| s.day()).collect();
+        assert_eq!(days[..20], (1..=20).collect::<Vec<u8>>());
+        assert!(days.is_sorted_by(|a, b| a < b));
+        for &day in &days {
+            assert_eq!(find(day).map(|s| s.day()), Some(day));
+        }
+        assert!(find(0).is_none());
+        assert!(find(26).is_none());
+    }
+}
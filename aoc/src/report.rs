@@ -0,0 +1,273 @@
+use crate::bench::nanos;
+use crate::cache::YEAR;
+use crate::check::Verdict;
+use aoc_common::parse::Strictness;
+use aoc_common::{DynSolution, ParseError};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// A line per part
+    Text,
+    /// A JSON object per part, one per line
+    Json,
+    /// Every day in a single JSON document
+    Summary,
+}
+
+/// Answer of one part.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PartRun {
+    pub part: u8,
+    pub answer: String,
+    /// [`Answer::kind`](aoc_common::Answer::kind) of the answer.
+    pub answer_type: &'static str,
+    #[serde(rename = "elapsed_ns", with = "nanos")]
+    pub elapsed: Duration,
+    /// Set by `check`.
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<Verdict>,
+    /// [`DynSolution::details`], when asked for with `--details`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<String>,
+}
+
+/// Parts of a day solved from one parsed input.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DayRun {
+    pub day: u8,
+    /// SHA-256 of the input, telling which input the answers belong to.
+    pub input_hash: String,
+    #[serde(rename = "parse_ns", with = "nanos")]
+    pub parse: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+impl DayRun {
+    pub fn solve(
+        solution: &dyn DynSolution,
+        parts: &[u8],
+        input: &str,
+        strictness: Strictness,
+        details: bool,
+    ) -> Result<Self, ParseError> {
+        let start = Instant::now();
+        let parsed = solution.parse_with(input, strictness)?;
+        let parse = start.elapsed();
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = solution.part(part, parsed.as_ref());
+                PartRun {
+                    part,
+                    elapsed: start.elapsed(),
+                    answer: answer.to_string(),
+                    answer_type: answer.kind(),
+                    verdict: None,
+                    details: details
+                        .then(|| solution.details(part, parsed.as_ref()))
+                        .flatten(),
+                }
+            })
+            .collect();
+        Ok(Self {
+            day: solution.day(),
+            input_hash: input_hash(input),
+            parse,
+            parts,
+        })
+    }
+    pub fn elapsed(&self) -> Duration {
+        self.parse + self.parts.iter().map(|part| part.elapsed).sum::<Duration>()
+    }
+}
+
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// A part with its day, the objects of `--format json`.
+#[derive(Serialize)]
+struct JsonLine<'a> {
+    day: u8,
+    #[serde(flatten)]
+    part: &'a PartRun,
+    input_hash: &'a str,
+}
+
+/// The document of `--format summary`.
+#[derive(Debug, Default, Serialize)]
+struct Summary {
+    year: u16,
+    #[serde(rename = "elapsed_ns", with = "nanos")]
+    elapsed: Duration,
+    days: Vec<DayRun>,
+    /// Days without a puzzle input.
+    skipped: Vec<u8>,
+}
+
+/// Writes the runs of days as they finish, or all of them at the end for a summary.
+pub struct Reporter<W: Write> {
+    format: Format,
+    out: W,
+    summary: Summary,
+}
+
+impl<W: Write> Reporter<W> {
+    pub fn new(format: Format, out: W) -> Self {
+        Self {
+            format,
+            out,
+            summary: Summary {
+                year: YEAR,
+                ..Summary::default()
+            },
+        }
+    }
+    pub fn skipped(&mut self, day: u8) -> io::Result<()> {
+        match self.format {
+            Format::Text => writeln!(self.out, "Day {day:>2}: no puzzle input, skipped")?,
+            Format::Json => {}
+            Format::Summary => self.summary.skipped.push(day),
+        }
+        Ok(())
+    }
+    pub fn day(&mut self, run: DayRun) -> io::Result<()> {
+        match self.format {
+            Format::Text => {
+                for part in &run.parts {
+                    write!(
+                        self.out,
+                        "Day {:>2}, part {}: {}",
+                        run.day, part.part, part.answer
+                    )?;
+                    match &part.verdict {
+                        Some(verdict) => writeln!(self.out, " ({verdict})")?,
+                        None => writeln!(self.out)?,
+                    }
+                    for line in part.details.iter().flat_map(|details| details.lines()) {
+                        writeln!(self.out, "    {line}")?;
+                    }
+                }
+            }
+            Format::Json => {
+                for part in &run.parts {
+                    let line = JsonLine {
+                        day: run.day,
+                        part,
+                        input_hash: &run.input_hash,
+                    };
+                    writeln!(self.out, "{}", serde_json::to_string(&line)?)?;
+                }
+            }
+            Format::Summary => {
+                self.summary.elapsed += run.elapsed();
+                self.summary.days.push(run);
+            }
+        }
+        Ok(())
+    }
+    pub fn finish(mut self) -> io::Result<()> {
+        if self.format == Format::Summary {
+            writeln!(self.out, "{}", serde_json::to_string_pretty(&self.summary)?)?;
+        }
+        self.out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::check::Verdict;
+    use crate::registry;
+    use crate::report::{DayRun, Format, Reporter, input_hash};
+    use aoc_common::parse::Strictness;
+    use serde_json::{Value, json};
+    use std::time::Duration;
+
+    fn example() -> DayRun {
+        let day1 = registry::find(1).unwrap();
+        let mut run =
+            DayRun::solve(day1, &[1, 2], "3   4\n4   3\n", Strictness::Strict, false).unwrap();
+        run.parse = Duration::from_nanos(5);
+        for part in &mut run.parts {
+            part.elapsed = Duration::from_nanos(10);
+        }
+        run
+    }
+    fn report(format: Format, verdict: Option<Verdict>) -> String {
+        let mut out = Vec::new();
+        let mut reporter = Reporter::new(format, &mut out);
+        reporter.skipped(2).unwrap();
+        let mut run = example();
+        run.parts[1].verdict = verdict;
+        reporter.day(run).unwrap();
+        reporter.finish().unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(
+            input_hash(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            report(Format::Text, Some(Verdict::Pass)),
+            "Day  2: no puzzle input, skipped\nDay  1, part 1: 0\nDay  1, part 2: 7 (pass)\n"
+        );
+    }
+    #[test]
+    fn test_details() {
+        let day2 = registry::find(2).unwrap();
+        let input = "7 6 4 2 1\n1 2 7 8 9\n";
+        let run = DayRun::solve(day2, &[2], input, Strictness::Strict, true).unwrap();
+        let mut out = Vec::new();
+        let mut reporter = Reporter::new(Format::Text, &mut out);
+        reporter.day(run).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Day  2, part 2: 1\n    7 6 4 2 1: safe\n    \
+             1 2 7 8 9: unsafe, level 3 changes by 5, more than 3\n"
+        );
+    }
+    #[test]
+    fn test_json() {
+        let verdict = Verdict::Fail {
+            expected: "25".to_string(),
+        };
+        let lines: Vec<Value> = report(Format::Json, Some(verdict))
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let hash = input_hash("3   4\n4   3\n");
+        assert_eq!(
+            lines,
+            [
+                json!({"day": 1, "part": 1, "answer": "0", "answer_type": "int",
+                    "elapsed_ns": 10, "input_hash": hash}),
+                json!({"day": 1, "part": 2, "answer": "7", "answer_type": "int",
+                    "elapsed_ns": 10, "input_hash": hash, "verdict": "fail", "expected": "25"}),
+            ]
+        );
+    }
+    #[test]
+    fn test_summary() {
+        let summary: Value = serde_json::from_str(&report(Format::Summary, None)).unwrap();
+        assert_eq!(summary["year"], 2024);
+        assert_eq!(summary["elapsed_ns"], 25);
+        assert_eq!(summary["skipped"], json!([2]));
+        assert_eq!(summary["days"][0]["parse_ns"], 5);
+        assert_eq!(summary["days"][0]["parts"][1]["answer"], "7");
+        assert_eq!(summary["days"][0]["parts"][1].get("verdict"), None);
+    }
+}
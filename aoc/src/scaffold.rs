@@ -0,0 +1,191 @@
+use crate::check::ANSWERS_FILE;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Crate copied by [`new_day`], relative to the workspace root.
+pub const TEMPLATE_DIR: &str = "template";
+
+const ANSWERS_STUB: &str = "# Known answers of the puzzle input, checked by `aoc check`
+# part1 = 0
+# part2 = 0
+";
+
+/// Creates `dayN` from the template crate and registers it with the workspace, the `aoc`
+/// dependencies and the solver registry. Returns the created and modified files.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let name = format!("day{day}");
+    let crate_dir = root.join(&name);
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()).into());
+    }
+    let template = root.join(TEMPLATE_DIR);
+    let read =
+        |path: PathBuf| fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()));
+
+    // Every edit is computed before writing anything, so that a failure leaves the tree untouched.
+    let mut files = vec![
+        (
+            crate_dir.join("Cargo.toml"),
+            read(template.join("Cargo.toml"))?
+                .replace("name = \"template\"", &format!("name = \"{name}\"")),
+        ),
+        (
+            crate_dir.join("src/lib.rs"),
+            read(template.join("src/lib.rs"))?
+                .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+                .replace("Template", &format!("Day{day}")),
+        ),
+        (crate_dir.join("resources/example-input.txt"), String::new()),
+        (
+            crate_dir.join("resources").join(ANSWERS_FILE),
+            ANSWERS_STUB.to_string(),
+        ),
+    ];
+    for (path, line) in [
+        (root.join("Cargo.toml"), format!("\"{name}\",")),
+        (
+            root.join("aoc/Cargo.toml"),
+            format!("{name} = {{ path = \"../{name}\" }}"),
+        ),
+        (
+            root.join("aoc/src/registry.rs"),
+            format!("&{name}::Day{day},"),
+        ),
+    ] {
+        let content = insert_day_line(&read(path.clone())?, day, &line)
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+        files.push((path, content));
+    }
+
+    for (path, content) in &files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, content)?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Day of a line listing one, like `"day7",`, `day7 = { .. }` or `&day7::Day7,`.
+fn listed_day(line: &str) -> Option<u8> {
+    let rest = line
+        .trim_start()
+        .trim_start_matches(['"', '&'])
+        .strip_prefix("day")?;
+    let digits = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    rest[..digits].parse().ok()
+}
+
+/// Inserts `line` among the lines listing days, keeping them ordered and reusing their indent.
+fn insert_day_line(content: &str, day: u8, line: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let listed: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, line)| listed_day(line).map(|day| (idx, day)))
+        .collect();
+    if listed.iter().any(|&(_, listed)| listed == day) {
+        return Err(format!("day {day} is already listed"));
+    }
+    let &(last, _) = listed.last().ok_or("no days are listed")?;
+    let at = listed
+        .iter()
+        .find(|&&(_, listed)| listed > day)
+        .map_or(last + 1, |&(idx, _)| idx);
+    let neighbour = lines[at.min(last)];
+    let indented = format!(
+        "{}{line}",
+        &neighbour[..neighbour.len() - neighbour.trim_start().len()]
+    );
+    lines.insert(at, &indented);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{insert_day_line, listed_day, new_day};
+    use std::fs;
+
+    #[test]
+    fn test_insert_day_line() {
+        assert_eq!(listed_day("    \"day12\","), Some(12));
+        assert_eq!(listed_day("day3 = { path = \"../day3\" }"), Some(3));
+        assert_eq!(listed_day("    &day1::Day1,"), Some(1));
+        assert_eq!(listed_day("    \"aoc-common\","), None);
+
+        let members = "members = [\n    \"aoc\",\n    \"day1\",\n    \"day3\",\n]\n";
+        assert_eq!(
+            insert_day_line(members, 2, "\"day2\",").unwrap(),
+            "members = [\n    \"aoc\",\n    \"day1\",\n    \"day2\",\n    \"day3\",\n]\n"
+        );
+        assert_eq!(
+            insert_day_line(members, 4, "\"day4\",").unwrap(),
+            "members = [\n    \"aoc\",\n    \"day1\",\n    \"day3\",\n    \"day4\",\n]\n"
+        );
+        assert!(insert_day_line(members, 3, "\"day3\",").is_err());
+        assert!(insert_day_line("members = []\n", 3, "\"day3\",").is_err());
+    }
+    #[test]
+    fn test_new_day() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+        fs::create_dir_all(root.join("template/src")).unwrap();
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("template/Cargo.toml"),
+            "[package]\nname = \"template\"\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("template/src/lib.rs"),
+            "pub struct Template;\nimpl Solution for Template {\n    const DAY: u8 = 0;\n}\n",
+        )
+        .unwrap();
+        fs::write(root.join("Cargo.toml"), "members = [\n    \"day1\",\n]\n").unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "day1 = { path = \"../day1\" }\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/registry.rs"),
+            "[\n    &day1::Day1,\n];\n",
+        )
+        .unwrap();
+
+        let written = new_day(root, 21).unwrap();
+        assert_eq!(written.len(), 7);
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert_eq!(read("day21/Cargo.toml"), "[package]\nname = \"day21\"\n");
+        assert_eq!(
+            read("day21/src/lib.rs"),
+            "pub struct Day21;\nimpl Solution for Day21 {\n    const DAY: u8 = 21;\n}\n"
+        );
+        assert_eq!(read("day21/resources/example-input.txt"), "");
+        assert!(
+            read("day21/resources/answers.toml")
+                .parse::<crate::check::KnownAnswers>()
+                .is_ok()
+        );
+        assert_eq!(
+            read("Cargo.toml"),
+            "members = [\n    \"day1\",\n    \"day21\",\n]\n"
+        );
+        assert_eq!(
+            read("aoc/Cargo.toml"),
+            "day1 = { path = \"../day1\" }\nday21 = { path = \"../day21\" }\n"
+        );
+        assert_eq!(
+            read("aoc/src/registry.rs"),
+            "[\n    &day1::Day1,\n    &day21::Day21,\n];\n"
+        );
+
+        assert!(new_day(root, 21).is_err());
+        fs::remove_dir_all(root.join("day21")).unwrap();
+        assert!(new_day(root, 21).is_err());
+        assert!(!root.join("day21").exists());
+    }
+}
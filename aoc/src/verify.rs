@@ -0,0 +1,25 @@
+use aoc_common::reference::DynReference;
+
+/// Days with a reference solver, ordered by day.
+pub static REFERENCES: &[&dyn DynReference] =
+    &[&day11::Day11, &day13::Day13, &day17::Day17, &day19::Day19];
+
+pub fn find(day: u8) -> Option<&'static dyn DynReference> {
+    REFERENCES
+        .iter()
+        .copied()
+        .find(|reference| reference.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry;
+    use crate::verify::REFERENCES;
+
+    #[test]
+    fn test_references() {
+        let days: Vec<u8> = REFERENCES.iter().map(|r| r.day()).collect();
+        assert!(days.is_sorted_by(|a, b| a < b));
+        assert!(days.iter().all(|&day| registry::find(day).is_some()));
+    }
+}
@@ -0,0 +1,315 @@
+use aoc_common::parse::{Line, Lines, Strictness};
+use aoc_common::{Answer, ParseError, Solution};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::io::BufRead;
+use std::str::FromStr;
+use tracing::warn;
+
+pub struct Day1;
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = LocationLists;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn parse_with(input: &str, strictness: Strictness) -> Result<Self::Parsed, ParseError> {
+        LocationLists::parse_with(input, strictness)
+    }
+    fn part1(lists: &Self::Parsed) -> Answer {
+        lists.total_distance().into()
+    }
+    fn part2(lists: &Self::Parsed) -> Answer {
+        lists.similarity_score().into()
+    }
+}
+
+/// Both columns of location ids, each sorted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocationLists {
+    left: Vec<i64>,
+    right: Vec<i64>,
+    /// Malformed lines skipped by a lenient parse.
+    diagnostics: Vec<ParseError>,
+}
+
+impl FromStr for LocationLists {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, Strictness::Strict)
+    }
+}
+
+/// Collects the pairs of the lines, and the errors of the malformed ones.
+#[derive(Debug, Default)]
+struct Collector {
+    diagnostics: Vec<ParseError>,
+}
+
+impl Collector {
+    /// Pair of ids starting `line`, `None` for blank lines, `#` comments and malformed lines.
+    /// Columns after the first two are ignored.
+    fn pair(&mut self, line: Line) -> Option<(i64, i64)> {
+        let text = line.text.trim_start();
+        if text.is_empty() || text.starts_with('#') {
+            return None;
+        }
+        let mut split = line.text.split_whitespace();
+        let mut id = || line.parse::<i64>(split.next().unwrap_or(line.end()), "a location id");
+        match id().and_then(|left| Ok((left, id()?))) {
+            Ok(pair) => Some(pair),
+            Err(error) => {
+                self.diagnostics.push(error);
+                None
+            }
+        }
+    }
+    /// Fails with every diagnostic when strict, logs them otherwise.
+    fn finish(self, strictness: Strictness) -> Result<Vec<ParseError>, ParseError> {
+        match strictness {
+            Strictness::Strict => match ParseError::combine(self.diagnostics) {
+                Some(error) => Err(error),
+                None => Ok(Vec::new()),
+            },
+            Strictness::Lenient => {
+                for error in &self.diagnostics {
+                    warn!(%error, "skipped a malformed line");
+                }
+                Ok(self.diagnostics)
+            }
+        }
+    }
+}
+
+impl LocationLists {
+    pub fn parse_with(input: &str, strictness: Strictness) -> Result<Self, ParseError> {
+        let mut collector = Collector::default();
+        let (mut left, mut right): (Vec<_>, Vec<_>) = Lines::new(input)
+            .filter_map(|line| collector.pair(line))
+            .unzip();
+        left.sort_unstable();
+        right.sort_unstable();
+        let diagnostics = collector.finish(strictness)?;
+        Ok(Self { left, right, diagnostics })
+    }
+    pub fn left(&self) -> &[i64] {
+        &self.left
+    }
+    pub fn right(&self) -> &[i64] {
+        &self.right
+    }
+    pub fn diagnostics(&self) -> &[ParseError] {
+        &self.diagnostics
+    }
+    /// Distances between the smallest ids of both lists, then the second smallest, and so on.
+    pub fn distances(&self) -> impl Iterator<Item = u64> + '_ {
+        self.left.iter().zip(&self.right).map(|(&l, &r)| l.abs_diff(r))
+    }
+    pub fn total_distance(&self) -> u64 {
+        self.distances().sum()
+    }
+    pub fn similarity_score(&self) -> i64 {
+        let occurrences: HashMap<i64, i64> = self.right.chunk_by(|a, b| a == b)
+            .map(|run| (run[0], run.len() as i64))
+            .collect();
+        self.left.iter()
+            .map(|l| l * occurrences.get(l).unwrap_or(&0))
+            .sum()
+    }
+    pub fn statistics(&self) -> Statistics {
+        let mut counts = Counts::default();
+        self.left.iter().zip(&self.right).for_each(|(&l, &r)| counts.add(l, r));
+        counts.statistics(self.diagnostics.clone())
+    }
+}
+
+/// Both answers and some more, from [`LocationLists::statistics`] or [`stream`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Statistics {
+    pub pairs: usize,
+    pub total_distance: u64,
+    pub similarity_score: i64,
+    /// Lower median of the distances, `None` without any pair.
+    pub median_distance: Option<u64>,
+    /// Number of left ids by how many times they appear in the right list.
+    pub matches: BTreeMap<usize, usize>,
+    /// Malformed lines skipped by a lenient parse.
+    pub diagnostics: Vec<ParseError>,
+}
+
+/// Statistics of lists read line by line, keeping only how many times each distinct id was seen,
+/// for inputs too large to hold in memory.
+pub fn stream(
+    mut input: impl BufRead,
+    strictness: Strictness,
+) -> Result<Statistics, Box<dyn Error>> {
+    let (mut counts, mut collector) = (Counts::default(), Collector::default());
+    let (mut text, mut number) = (String::new(), 0);
+    while input.read_line(&mut text)? > 0 {
+        number += 1;
+        let line = Line { number, text: text.trim_end_matches(['\n', '\r']) };
+        if let Some((l, r)) = collector.pair(line) {
+            counts.add(l, r);
+        }
+        text.clear();
+    }
+    Ok(counts.statistics(collector.finish(strictness)?))
+}
+
+/// Occurrences of every id of each list, sorted by id.
+#[derive(Debug, Default)]
+struct Counts {
+    pairs: usize,
+    left: BTreeMap<i64, usize>,
+    right: BTreeMap<i64, usize>,
+}
+
+impl Counts {
+    fn add(&mut self, left: i64, right: i64) {
+        self.pairs += 1;
+        *self.left.entry(left).or_default() += 1;
+        *self.right.entry(right).or_default() += 1;
+    }
+    fn statistics(&self, diagnostics: Vec<ParseError>) -> Statistics {
+        // Pairs up runs of equal ids instead of single ids
+        let mut distances = BTreeMap::<u64, usize>::new();
+        let mut left = self.left.iter().map(|(&id, &n)| (id, n));
+        let mut right = self.right.iter().map(|(&id, &n)| (id, n));
+        let (mut l, mut r) = (left.next(), right.next());
+        while let (Some((l_id, l_n)), Some((r_id, r_n))) = (l, r) {
+            let n = l_n.min(r_n);
+            *distances.entry(l_id.abs_diff(r_id)).or_default() += n;
+            l = if l_n > n { Some((l_id, l_n - n)) } else { left.next() };
+            r = if r_n > n { Some((r_id, r_n - n)) } else { right.next() };
+        }
+        let middle = self.pairs.saturating_sub(1) / 2;
+        let median_distance = distances.iter()
+            .scan(0, |seen, (&distance, &n)| {
+                *seen += n;
+                Some((distance, *seen))
+            })
+            .find(|&(_, seen)| seen > middle)
+            .map(|(distance, _)| distance);
+        let mut matches = BTreeMap::new();
+        for (id, &n) in &self.left {
+            *matches.entry(self.right.get(id).copied().unwrap_or(0)).or_default() += n;
+        }
+        Statistics {
+            pairs: self.pairs,
+            total_distance: distances.iter().map(|(&distance, &n)| distance * n as u64).sum(),
+            similarity_score: self.left.iter()
+                .map(|(id, &n)| id * (n * self.right.get(id).unwrap_or(&0)) as i64)
+                .sum(),
+            median_distance,
+            matches,
+            diagnostics,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day1, LocationLists, Statistics, stream};
+    use aoc_common::parse::Strictness;
+    use aoc_common::{Answer, Solution, parse_input, read_input};
+    use proptest::prelude::*;
+    use std::collections::BTreeMap;
+
+    fn example() -> LocationLists {
+        parse_input!("example-input.txt").unwrap()
+    }
+    #[test]
+    fn test_distance() {
+        assert_eq!(11, example().total_distance());
+    }
+    #[test]
+    fn test_similarity_score() {
+        assert_eq!(31, example().similarity_score());
+    }
+    #[test]
+    fn test_statistics() {
+        let expected = Statistics {
+            pairs: 6,
+            total_distance: 11,
+            similarity_score: 31,
+            median_distance: Some(1),
+            matches: BTreeMap::from([(0, 2), (1, 1), (3, 3)]),
+            diagnostics: Vec::new(),
+        };
+        assert_eq!(example().statistics(), expected);
+        let input = read_input!("example-input.txt").unwrap();
+        assert_eq!(stream(input, Strictness::Strict).unwrap(), expected);
+        assert_eq!(stream("".as_bytes(), Strictness::Strict).unwrap().median_distance, None);
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = "3   4\n4 x\n".parse::<LocationLists>().unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: expected a location id, found \"x\"");
+        assert_eq!("3\n".parse::<LocationLists>().unwrap_err().column, 2);
+        let error = stream("3   4\r\n\n4 x\r\n".as_bytes(), Strictness::Strict).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 3: expected a location id, found \"x\"");
+    }
+    #[test]
+    fn test_tolerant_parse() {
+        let input = "# left right\n3   4   extra\n\n  -2 x\n-1   3\n\t# indented\n5\n";
+        let error = LocationLists::parse_with(input, Strictness::Strict).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 6: expected a location id, found \"x\"\n\
+             line 7, column 2: expected a location id, found end of line"
+        );
+        let lists = LocationLists::parse_with(input, Strictness::Lenient).unwrap();
+        assert_eq!((lists.left(), lists.right()), (&[-1, 3][..], &[3, 4][..]));
+        assert_eq!(lists.diagnostics().len(), 2);
+        assert_eq!(lists.total_distance(), 5);
+        let statistics = stream(input.as_bytes(), Strictness::Lenient).unwrap();
+        assert_eq!(statistics, lists.statistics());
+        assert_eq!(statistics.similarity_score, 3);
+        assert!(stream(input.as_bytes(), Strictness::Strict).is_err());
+        assert!(Day1::parse(input).is_err());
+    }
+
+    fn arb_location_lists() -> impl Strategy<Value = Vec<(i64, i64)>> {
+        prop::collection::vec((-20..50i64, -20..50i64), 0..20)
+    }
+    fn brute_distance(pairs: &[(i64, i64)]) -> u64 {
+        let (mut left, mut right): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+        let mut distance = 0;
+        while let (Some(l), Some(r)) = (left.iter().min().copied(), right.iter().min().copied()) {
+            distance += l.abs_diff(r);
+            left.remove(left.iter().position(|&x| x == l).unwrap());
+            right.remove(right.iter().position(|&x| x == r).unwrap());
+        }
+        distance
+    }
+    fn brute_median_distance(pairs: &[(i64, i64)]) -> Option<u64> {
+        let (mut left, mut right): (Vec<_>, Vec<_>) = pairs.iter().copied().unzip();
+        left.sort();
+        right.sort();
+        let mut distances: Vec<_> = left.iter().zip(&right).map(|(l, r)| l.abs_diff(*r)).collect();
+        distances.sort();
+        distances.get(distances.len().saturating_sub(1) / 2).copied()
+    }
+    fn brute_similarity(pairs: &[(i64, i64)]) -> i64 {
+        pairs
+            .iter()
+            .map(|&(l, _)| l * pairs.iter().filter(|&&(_, r)| r == l).count() as i64)
+            .sum()
+    }
+    proptest! {
+        #[test]
+        fn prop_matches_brute_force(pairs in arb_location_lists()) {
+            let input: String = pairs.iter().map(|(l, r)| format!("{l}   {r}\n")).collect();
+            let lists = Day1::parse(&input).unwrap();
+            prop_assert_eq!(Day1::part1(&lists), Answer::from(brute_distance(&pairs)));
+            prop_assert_eq!(Day1::part2(&lists), Answer::from(brute_similarity(&pairs)));
+            let statistics = lists.statistics();
+            prop_assert_eq!(statistics.total_distance, brute_distance(&pairs));
+            prop_assert_eq!(statistics.similarity_score, brute_similarity(&pairs));
+            prop_assert_eq!(statistics.median_distance, brute_median_distance(&pairs));
+            prop_assert_eq!(statistics.matches.values().sum::<usize>(), pairs.len());
+            prop_assert_eq!(stream(input.as_bytes(), Strictness::Strict).unwrap(), statistics);
+        }
+    }
+}
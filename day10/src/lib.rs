@@ -0,0 +1,107 @@
+use aoc_common::grid::Cell;
+use aoc_common::parse::Lines;
+use aoc_common::{Answer, Grid, ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub struct Day10;
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = TopoMap;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(map: &Self::Parsed) -> Answer {
+        map.trailhead_scores().into()
+    }
+    fn part2(map: &Self::Parsed) -> Answer {
+        map.trailhead_rating().into()
+    }
+}
+
+pub struct TopoMap {
+    elevations: Grid<u8>,
+    summits: Vec<Cell>,
+    trailheads: Vec<Cell>,
+}
+impl TopoMap {
+    fn reachable_summits(&self, pos: Cell, start_elevation: u8) -> Vec<Cell> {
+        let mut reachable: Vec<Cell> = Vec::new();
+        for new_pos in self.elevations.neighbours4(pos) {
+            match self.elevations[new_pos] {
+                x if x == 9 && start_elevation == 8 => reachable.push(new_pos),
+                x if x == start_elevation + 1 => {
+                    reachable.append(&mut self.reachable_summits(new_pos, x))
+                }
+                _ => {}
+            }
+        }
+        reachable
+    }
+    pub fn trailhead_scores(&self) -> usize {
+        self.trailheads
+            .iter()
+            .map(|&th| {
+                self.reachable_summits(th, 0)
+                    .into_iter()
+                    .collect::<HashSet<_>>()
+                    .len()
+            })
+            .sum()
+    }
+    pub fn trailhead_rating(&self) -> usize {
+        self.trailheads
+            .iter()
+            .map(|&th| self.reachable_summits(th, 0).len())
+            .sum()
+    }
+}
+impl Display for TopoMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.elevations)?;
+        writeln!(f, "Summits: {:?}", self.summits)?;
+        writeln!(f, "Trailheads: {:?}", self.trailheads)?;
+        Ok(())
+    }
+}
+impl FromStr for TopoMap {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let elevations = Grid::parse(
+            &mut Lines::new(input),
+            |char| char.to_digit(10).map(|elevation| elevation as u8),
+            "an elevation digit",
+        )?;
+        let with_elevation = |elevation| {
+            elevations
+                .iter()
+                .filter(|&(_, &e)| e == elevation)
+                .map(|(pos, _)| pos)
+                .collect()
+        };
+        Ok(Self {
+            summits: with_elevation(9),
+            trailheads: with_elevation(0),
+            elevations,
+        })
+    }
+}
+#[cfg(test)]
+mod tests {
+
+    use crate::TopoMap;
+    use aoc_common::parse_input;
+
+    #[test]
+    fn test_part1() {
+        let topo: TopoMap = parse_input!("example-input.txt").unwrap();
+        assert_eq!(topo.trailhead_scores(), 36);
+    }
+    #[test]
+    fn test_part2() {
+        let topo: TopoMap = parse_input!("example-input.txt").unwrap();
+        assert_eq!(topo.trailhead_rating(), 81);
+    }
+}
@@ -0,0 +1,190 @@
+use aoc_common::parse::Lines;
+use aoc_common::{Answer, ParseError, Solution};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct Day11;
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = Grid;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(grid: &Self::Parsed) -> Answer {
+        let mut grid = grid.clone();
+        for _ in 0..25 {
+            grid.blink();
+        }
+        grid.stone_count().into()
+    }
+    fn part2(grid: &Self::Parsed) -> Answer {
+        let mut grid = grid.clone();
+        for _ in 0..75 {
+            grid.blink();
+        }
+        grid.stone_count().into()
+    }
+}
+
+#[derive(Ord, PartialOrd, Eq, PartialEq, Hash, Copy, Clone, Debug)]
+struct Stone {
+    number: u128,
+}
+impl Stone {
+    pub fn blink(self) -> (Stone, Option<Stone>) {
+        match self.number {
+            0 => (Stone { number: 1 }, None),
+            x if Self::digits_count(x) % 2 == 0 => {
+                let half_digit = Self::digits_count(x) / 2;
+                (
+                    Stone {
+                        number: x / 10u128.pow(half_digit as u32),
+                    },
+                    Some(Stone {
+                        number: x % 10u128.pow(half_digit as u32),
+                    }),
+                )
+            }
+            x => (Stone { number: x * 2024 }, None),
+        }
+    }
+
+    fn digits_count(num: u128) -> usize {
+        (0..).take_while(|i| 10u128.pow(*i) <= num).count()
+    }
+}
+#[derive(Clone)]
+pub struct Grid {
+    stones: HashMap<Stone, usize>,
+}
+impl Grid {
+    pub fn blink(&mut self) {
+        let mut new_stones = HashMap::new();
+        for (stone, count) in self.stones.iter() {
+            match stone.blink() {
+                (left, Some(right)) => {
+                    *new_stones.entry(left).or_insert(0) += count;
+                    *new_stones.entry(right).or_insert(0) += count;
+                }
+                (stone, None) => {
+                    *new_stones.entry(stone).or_insert(0) += count;
+                }
+            }
+        }
+        self.stones = new_stones;
+    }
+    pub fn stone_count(&self) -> usize {
+        self.stones.values().sum()
+    }
+}
+impl FromStr for Grid {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut stones = HashMap::new();
+        for line in Lines::new(input) {
+            for number in line.text.split_whitespace() {
+                let stone = Stone {
+                    number: line.parse(number, "a stone number")?,
+                };
+                *stones.entry(stone).or_insert(0) += 1;
+            }
+        }
+        Ok(Self { stones })
+    }
+}
+/// Blinks at every stone on its own, splitting their decimal representation.
+#[cfg(any(test, feature = "reference"))]
+pub mod reference {
+    use crate::{Day11, Grid};
+    use aoc_common::Answer;
+    use aoc_common::reference::Reference;
+    use proptest::prelude::*;
+
+    fn blink(number: u128) -> Vec<u128> {
+        let digits = number.to_string();
+        match number {
+            0 => vec![1],
+            _ if digits.len().is_multiple_of(2) => {
+                let (left, right) = digits.split_at(digits.len() / 2);
+                vec![left.parse().unwrap(), right.parse().unwrap()]
+            }
+            _ => vec![number * 2024],
+        }
+    }
+    impl Reference for Day11 {
+        fn inputs() -> BoxedStrategy<String> {
+            prop::collection::vec(0..10_000u128, 1..4)
+                .prop_map(|stones| {
+                    stones.iter().map(u128::to_string).collect::<Vec<_>>().join(" ")
+                })
+                .boxed()
+        }
+        fn reference_part1(grid: &Grid) -> Option<Answer> {
+            let mut stones: Vec<u128> = grid.stones.iter()
+                .flat_map(|(stone, &count)| std::iter::repeat_n(stone.number, count))
+                .collect();
+            for _ in 0..25 {
+                stones = stones.into_iter().flat_map(blink).collect();
+            }
+            Some(stones.len().into())
+        }
+        fn reference_part2(_: &Grid) -> Option<Answer> {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day11, Grid, Stone};
+    use aoc_common::parse_input;
+    use aoc_common::reference::verify;
+    #[test]
+    fn test_helpers() {
+        assert_eq!(Stone::digits_count(11221), 5);
+        assert_eq!(Stone::digits_count(125), 3);
+        assert_eq!(Stone::digits_count(253000), 6);
+        assert_eq!(Stone::digits_count(1000), 4);
+
+        {
+            let stone = Stone { number: 253000 }.blink();
+            assert_eq!(stone, (Stone { number: 253 }, Some(Stone { number: 0 })));
+        }
+        {
+            let stone = Stone { number: 512072 }.blink();
+            assert_eq!(stone, (Stone { number: 512 }, Some(Stone { number: 72 })));
+        }
+        {
+            let stone = Stone { number: 14168 }.blink();
+            assert_eq!(stone, (Stone { number: 28676032 }, None));
+        }
+    }
+    #[test]
+    fn test_part1() {
+        let mut grid: Grid = parse_input!("example-input.txt").unwrap();
+        assert_eq!(grid.stone_count(), 5);
+        for _ in 0..1 {
+            grid.blink();
+        }
+        assert_eq!(grid.stone_count(), 7);
+        let mut grid = "125 17".parse::<Grid>().unwrap();
+        for _ in 0..6 {
+            grid.blink();
+        }
+        assert_eq!(grid.stone_count(), 22);
+        let mut grid = "125 17".parse::<Grid>().unwrap();
+        for _ in 0..25 {
+            grid.blink();
+        }
+        assert_eq!(grid.stone_count(), 55312);
+    }
+    #[test]
+    fn test_part2() {
+        let _grid: Grid = parse_input!("example-input.txt").unwrap();
+    }
+    #[test]
+    fn test_reference() {
+        assert_eq!(verify::<Day11>(16), Ok(()));
+    }
+}
@@ -1,23 +1,29 @@
-#![feature(unsigned_signed_diff)]
-
+use aoc_common::geometry::Compass::{North, NorthEast, NorthWest, West};
+use aoc_common::geometry::IPoint;
+use aoc_common::parse::Lines;
+use aoc_common::{Answer, Grid, ParseError, Solution};
 use std::fmt::Display;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::mem::swap;
-use std::path::PathBuf;
+use std::str::FromStr;
+use tracing::trace;
 
-fn main() {
-    let _reader = read_input("puzzle-input.txt");
-    let farm = Farm::from(_reader);
-    println!("Total fencing cost: {}", farm.fencing_cost());
-    println!("Total discounted cost: {}", farm.discounted_cost())
+pub struct Day12;
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = Farm;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(farm: &Self::Parsed) -> Answer {
+        farm.fencing_cost().into()
+    }
+    fn part2(farm: &Self::Parsed) -> Answer {
+        farm.discounted_cost().into()
+    }
 }
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
-struct Position {
-    x: isize,
-    y: isize,
-}
+type Position = IPoint;
 #[derive(Debug)]
 struct Field {
     crop: char,
@@ -40,26 +46,26 @@ impl Field {
                 self.corners = 4;
             },
             _ => {
-                match (self.occupies.iter().find(|&&p| p == Position { x: pos.x - 1, y: pos.y })
-                       , self.occupies.iter().find(|&&p| p == Position { x: pos.x, y: pos.y - 1 })) {
+                match (self.occupies.iter().find(|&&p| p == pos + West)
+                       , self.occupies.iter().find(|&&p| p == pos + North)) {
                     (Some(_), Some(_)) => {
                         self.corners -= 2;
-                        if self.occupies.iter().find(|&&p| p == Position { x: pos.x +1, y: pos.y - 1 }).is_some() {
+                        if self.occupies.iter().find(|&&p| p == pos + NorthEast).is_some() {
                             self.corners += 2;
                         }
                     }
                     (None, Some(_)) => {
                         self.perimeter += 2;
-                        if self.occupies.iter().find(|&&p| p == Position { x: pos.x +1, y: pos.y - 1 }).is_some() {
+                        if self.occupies.iter().find(|&&p| p == pos + NorthEast).is_some() {
                             self.corners += 2;
                         }
-                        if self.occupies.iter().find(|&&p| p == Position { x: pos.x -1, y: pos.y - 1 }).is_some() {
+                        if self.occupies.iter().find(|&&p| p == pos + NorthWest).is_some() {
                             self.corners += 2;
                         }
                     }
                     (Some(_), None) => {
                         self.perimeter += 2;
-                        if self.occupies.iter().find(|&&p| p == Position { x: pos.x -1, y: pos.y - 1 }).is_some() {
+                        if self.occupies.iter().find(|&&p| p == pos + NorthWest).is_some() {
                             self.corners += 2;
                         }
                     }
@@ -83,7 +89,7 @@ impl Field {
         field
     }
     pub fn merge_at(&mut self, mut other: Field, pos: Position) {
-        let top_right = Position{x: pos.x +1, y: pos.y - 1};
+        let top_right = pos + NorthEast;
         if self.occupies.contains(&top_right) || other.occupies.contains(&top_right) {
             self.corners += 2;
         }
@@ -94,8 +100,8 @@ impl Field {
         self.occupies.push(pos)
     }
 }
-#[derive(Debug)]
-struct Farm {
+#[derive(Debug, Default)]
+pub struct Farm {
     fields: Vec<Field>,
 }
 impl Display for Farm {
@@ -121,7 +127,7 @@ impl Farm {
     }
     fn add_plot(&mut self, crop: char, pos: Position) {
 
-        match (self.field_with_pos(crop, Position{ x: pos.x-1, y: pos.y }),self.field_with_pos(crop, Position{ x: pos.x, y: pos.y-1 })) {
+        match (self.field_with_pos(crop, pos + West),self.field_with_pos(crop, pos + North)) {
             (None, None) => self.fields.push(Field::new_with_crop(crop, pos)),
             (Some(idx), None) => self.fields[idx].add(pos),
             (None, Some(idx)) => self.fields[idx].add(pos),
@@ -136,36 +142,23 @@ impl Farm {
         }
     }
 }
-impl Default for Farm {
-    fn default() -> Self {
-        Self {
-            fields: Vec::new(),
-        }
-    }
-
-}
 
-impl<Reader> From<Reader> for Farm
-where
-    Reader: BufRead,
-{
-    fn from(reader: Reader) -> Self {
+impl FromStr for Farm {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
         let mut farm = Farm::default();
-        for (y, line) in reader.lines().map_while(|line| line.ok()).enumerate() {
-            for (x, char) in line.chars().enumerate() {
-                farm.add_plot(char, Position { x: x as isize, y: y as isize });
-                // println!("{char} => {farm}")
-            }
+        let plots = Grid::parse(
+            &mut Lines::new(input),
+            |c| c.is_ascii_uppercase().then_some(c),
+            "a plant type letter",
+        )?;
+        for (cell, &char) in plots.iter() {
+            farm.add_plot(char, cell.signed());
+            trace!(%char, %farm, "added plot");
         }
-        farm
+        Ok(farm)
     }
 }
-fn read_input(name: &str) -> BufReader<File> {
-    let mut example_data = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    example_data.push(format!("resources/{name}"));
-
-    BufReader::new(File::open(example_data).unwrap())
-}
 #[cfg(test)]
 mod tests {
     use crate::Farm;
@@ -175,7 +168,7 @@ mod tests {
 BBCD
 BBCC
 EEEC";
-        let farm = Farm::from(example.as_bytes());
+        let farm = example.parse::<Farm>().unwrap();
         assert_eq!(farm.fencing_cost(), 140);
         assert_eq!(farm.discounted_cost(), 80);
     }
@@ -186,7 +179,7 @@ OXOXO
 OOOOO
 OXOXO
 OOOOO";
-        let farm = Farm::from(example.as_bytes());
+        let farm = example.parse::<Farm>().unwrap();
         assert_eq!(farm.fencing_cost(), 772);
         assert_eq!(farm.discounted_cost(), 436);
     }
@@ -202,7 +195,7 @@ VVIIICJJEE
 MIIIIIJJEE
 MIIISIJEEE
 MMMISSJEEE";
-        let farm = Farm::from(example.as_bytes());
+        let farm = example.parse::<Farm>().unwrap();
         assert_eq!(farm.fencing_cost(), 1930);
         assert_eq!(farm.discounted_cost(), 1206);
     }
@@ -213,7 +206,7 @@ EXXXX
 EEEEE
 EXXXX
 EEEEE";
-        let farm = Farm::from(example.as_bytes());
+        let farm = example.parse::<Farm>().unwrap();
         assert_eq!(farm.fencing_cost(), 692);
         assert_eq!(farm.discounted_cost(), 236);
     }
@@ -225,7 +218,7 @@ AAABBA
 ABBAAA
 ABBAAA
 AAAAAA";
-        let farm = Farm::from(example.as_bytes());
+        let farm = example.parse::<Farm>().unwrap();
         assert_eq!(farm.fencing_cost(), 1184);
         assert_eq!(farm.discounted_cost(), 368);
     }
@@ -0,0 +1,275 @@
+use aoc_common::geometry::IPoint;
+use aoc_common::parse::{Line, Lines};
+use aoc_common::{Answer, ParseError, Solution};
+use std::str::FromStr;
+
+pub struct Day13;
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = Machines;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(machines: &Self::Parsed) -> Answer {
+        machines.used_tokens(Some(100)).into()
+    }
+    fn part2(machines: &Self::Parsed) -> Answer {
+        let mut machines = machines.clone();
+        machines.machines.iter_mut().for_each(|m| {
+            m.prize.x += 10000000000000;
+            m.prize.y += 10000000000000;
+        });
+        machines.used_tokens(None).into()
+    }
+}
+
+/// How far the claw moves with a single press.
+type Button = IPoint;
+type Position = IPoint;
+#[derive(Debug, Copy, Clone)]
+struct ClawMachine {
+    button_a: Button,
+    button_b: Button,
+    prize: Position,
+}
+
+impl ClawMachine {
+    /// Tokens needed to win the prize, pressing each button at most `max_presses` times.
+    fn cheapest_combination(&self, max_presses: Option<isize>) -> Option<isize> {
+        let (a, b, prize) = (self.button_a, self.button_b, self.prize);
+        let det = a.x * b.y - a.y * b.x;
+        if det == 0 {
+            return self.cheapest_parallel(max_presses);
+        }
+        // Cramer's rule, the presses have to be whole numbers
+        let a_numerator = prize.x * b.y - prize.y * b.x;
+        let b_numerator = a.x * prize.y - a.y * prize.x;
+        if a_numerator % det != 0 || b_numerator % det != 0 {
+            return None;
+        }
+        let (a_pressed, b_pressed) = (a_numerator / det, b_numerator / det);
+        let presses = 0..=max_presses.unwrap_or(isize::MAX);
+        (presses.contains(&a_pressed) && presses.contains(&b_pressed))
+            .then_some(a_pressed * 3 + b_pressed)
+    }
+    /// Buttons moving along the same line: the prize has to be on it, and the presses solve a
+    /// single equation along one axis.
+    fn cheapest_parallel(&self, max_presses: Option<isize>) -> Option<isize> {
+        let (a, b, prize) = (self.button_a, self.button_b, self.prize);
+        let zero = Button::default();
+        if a == zero && b == zero {
+            return (prize == zero).then_some(0);
+        }
+        let cross = |button: Button| button.x * prize.y - button.y * prize.x;
+        if cross(a) != 0 || cross(b) != 0 {
+            return None;
+        }
+        match a.x != 0 || b.x != 0 {
+            true => cheapest_presses(a.x, b.x, prize.x, max_presses),
+            false => cheapest_presses(a.y, b.y, prize.y, max_presses),
+        }
+    }
+}
+
+/// Greatest common divisor of `a` and `b`, with `x` and `y` such that `a * x + b * y` is it.
+fn extended_gcd(a: isize, b: isize) -> (isize, isize, isize) {
+    match b {
+        0 => (a, 1, 0),
+        _ => {
+            let (gcd, x, y) = extended_gcd(b, a % b);
+            (gcd, y, x - a / b * y)
+        }
+    }
+}
+
+/// Quotient rounded towards negative infinity.
+fn floor_div(n: isize, d: isize) -> isize {
+    match n % d != 0 && (n < 0) != (d < 0) {
+        true => n / d - 1,
+        false => n / d,
+    }
+}
+fn ceil_div(n: isize, d: isize) -> isize {
+    -floor_div(-n, d)
+}
+
+/// Fewest tokens for `a_pressed * a + b_pressed * b == target`, both presses between 0 and
+/// `max_presses`.
+fn cheapest_presses(
+    a: isize,
+    b: isize,
+    target: isize,
+    max_presses: Option<isize>,
+) -> Option<isize> {
+    let (gcd, x, y) = extended_gcd(a, b);
+    if target % gcd != 0 {
+        return None;
+    }
+    // Every solution is (a0 + t * a_step, b0 + t * b_step)
+    let (a0, b0) = (x * (target / gcd), y * (target / gcd));
+    let (a_step, b_step) = (b / gcd, -a / gcd);
+    let (mut low, mut high) = (isize::MIN, isize::MAX);
+    for (start, step) in [(a0, a_step), (b0, b_step)] {
+        // 0 <= start + t * step <= max_presses
+        let above_max = max_presses.map(|max| max - start);
+        match step.signum() {
+            0 if start < 0 || above_max.is_some_and(|n| n < 0) => return None,
+            0 => {}
+            1 => {
+                low = low.max(ceil_div(-start, step));
+                high = above_max.map_or(high, |n| high.min(floor_div(n, step)));
+            }
+            _ => {
+                high = high.min(floor_div(-start, step));
+                low = above_max.map_or(low, |n| low.max(ceil_div(n, step)));
+            }
+        }
+    }
+    if low > high {
+        return None;
+    }
+    // Tokens change linearly with t, and can't go below 0: the cheapest end is bounded
+    let t = match (3 * a_step + b_step).signum() {
+        1 => low,
+        -1 => high,
+        _ if low == isize::MIN => high,
+        _ => low,
+    };
+    Some((a0 + t * a_step) * 3 + b0 + t * b_step)
+}
+
+#[derive(Clone)]
+pub struct Machines {
+    machines: Vec<ClawMachine>,
+}
+impl Machines{
+    pub fn used_tokens(&self, max_presses: Option<isize>) -> isize {
+        self.machines.iter().filter_map(|m| m.cheapest_combination(max_presses)).sum()
+    }
+}
+fn parse_point(line: Line, prefix: &str, sign: &str) -> Result<IPoint, ParseError> {
+    let coordinates = line.strip_prefix(line.text, prefix)?;
+    let (x, y) = coordinates.split_once(", ").ok_or_else(|| line.error(line.end(), "\", \""))?;
+    let x = line.strip_prefix(x, &format!("X{sign}"))?;
+    let y = line.strip_prefix(y, &format!("Y{sign}"))?;
+    Ok(IPoint::new(line.parse(x, "a number")?, line.parse(y, "a number")?))
+}
+impl FromStr for Machines {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Lines::new(input);
+        let mut machines = Vec::new();
+        while let Some(button_a) = lines.find(|line| !line.text.is_empty()) {
+            machines.push(ClawMachine {
+                button_a: parse_point(button_a, "Button A: ", "+")?,
+                button_b: parse_point(lines.expect("Button B")?, "Button B: ", "+")?,
+                prize: parse_point(lines.expect("a prize")?, "Prize: ", "=")?,
+            });
+        }
+        Ok(Self { machines })
+    }
+}
+/// Tries every number of presses up to 100 of both buttons.
+#[cfg(any(test, feature = "reference"))]
+pub mod reference {
+    use crate::{ClawMachine, Day13, Machines};
+    use aoc_common::Answer;
+    use aoc_common::reference::Reference;
+    use proptest::prelude::*;
+
+    /// Buttons move an even number of steps along X, so that an odd offset of the prize makes it
+    /// unreachable. Some buttons are parallel, and some prizes need more than 100 or negative
+    /// presses.
+    fn arb_machine() -> impl Strategy<Value = String> {
+        let button = || (1..50isize, 1..100isize).prop_map(|(x, y)| (x * 2, y));
+        let parallel = (button(), 1..4isize, 1..4isize)
+            .prop_map(|((x, y), a, b)| ((x * a, y * a), (x * b, y * b)));
+        let buttons = prop_oneof![(button(), button()), parallel];
+        (buttons, -20..=150isize, -20..=150isize, 0..2isize)
+            .prop_map(|((a, b), a_pressed, b_pressed, offset)| {
+                let prize_x = a.0 * a_pressed + b.0 * b_pressed + offset;
+                let prize_y = a.1 * a_pressed + b.1 * b_pressed;
+                format!(
+                    "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={prize_x}, Y={prize_y}\n",
+                    a.0, a.1, b.0, b.1
+                )
+            })
+    }
+    fn cheapest(machine: &ClawMachine) -> Option<isize> {
+        (0..=100)
+            .flat_map(|a_pressed| (0..=100).map(move |b_pressed| (a_pressed, b_pressed)))
+            .filter(|&(a_pressed, b_pressed)| {
+                machine.button_a * a_pressed + machine.button_b * b_pressed == machine.prize
+            })
+            .map(|(a_pressed, b_pressed)| a_pressed * 3 + b_pressed)
+            .min()
+    }
+    impl Reference for Day13 {
+        fn inputs() -> BoxedStrategy<String> {
+            prop::collection::vec(arb_machine(), 1..5)
+                .prop_map(|machines| machines.join("\n"))
+                .boxed()
+        }
+        fn reference_part1(machines: &Machines) -> Option<Answer> {
+            Some(machines.machines.iter().filter_map(cheapest).sum::<isize>().into())
+        }
+        fn reference_part2(_: &Machines) -> Option<Answer> {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ClawMachine, Day13, Machines};
+    use aoc_common::geometry::IPoint;
+    use aoc_common::parse_input;
+    use aoc_common::reference::verify;
+
+    #[test]
+    fn test_part1() {
+        let machines: Machines = parse_input!("example-input.txt").unwrap();
+        assert_eq!(machines.machines[1].cheapest_combination(Some(100)), None);
+        assert_eq!(machines.machines[3].cheapest_combination(Some(100)), None);
+        assert_eq!(machines.machines[0].cheapest_combination(Some(100)), Some(280));
+        assert_eq!(machines.machines[2].cheapest_combination(Some(100)), Some(200));
+    }
+    #[test]
+    fn test_part2() {
+        let mut machines: Machines = parse_input!("example-input.txt").unwrap();
+        machines.machines.iter_mut().for_each(|m| {
+            m.prize.x += 10000000000000;
+            m.prize.y += 10000000000000;
+        });
+        assert_eq!(machines.machines[1].cheapest_combination(None), Some(459236326669));
+        assert_eq!(machines.machines[3].cheapest_combination(None), Some(416082282239));
+        assert_eq!(machines.machines[0].cheapest_combination(None), None);
+        assert_eq!(machines.machines[2].cheapest_combination(None), None);
+    }
+    #[test]
+    fn test_edge_cases() {
+        let machine = |a: (isize, isize), b: (isize, isize), prize: (isize, isize)| ClawMachine {
+            button_a: IPoint::new(a.0, a.1),
+            button_b: IPoint::new(b.0, b.1),
+            prize: IPoint::new(prize.0, prize.1),
+        };
+        // parallel buttons
+        assert_eq!(machine((2, 1), (4, 2), (8, 4)).cheapest_combination(Some(100)), Some(2));
+        assert_eq!(machine((2, 1), (4, 2), (10, 5)).cheapest_combination(Some(100)), Some(5));
+        assert_eq!(machine((6, 3), (2, 1), (6, 3)).cheapest_combination(Some(100)), Some(3));
+        assert_eq!(machine((8, 4), (2, 1), (400, 200)).cheapest_combination(Some(100)), Some(150));
+        assert_eq!(machine((2, 1), (4, 2), (3, 1)).cheapest_combination(None), None);
+        assert_eq!(machine((4, 2), (4, 2), (6, 3)).cheapest_combination(None), None);
+        // too many or negative presses
+        assert_eq!(machine((1, 0), (0, 1), (101, 1)).cheapest_combination(Some(100)), None);
+        assert_eq!(machine((1, 0), (0, 1), (101, 1)).cheapest_combination(None), Some(304));
+        assert_eq!(machine((1, 0), (0, 1), (-1, 1)).cheapest_combination(None), None);
+        assert_eq!(machine((2, 1), (4, 2), (402, 201)).cheapest_combination(Some(100)), Some(103));
+    }
+
+    #[test]
+    fn test_reference() {
+        assert_eq!(verify::<Day13>(64), Ok(()));
+    }
+}
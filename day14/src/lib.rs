@@ -0,0 +1,307 @@
+use aoc_common::geometry::{Compass, IPoint};
+use aoc_common::parse::{Line, parse_lines};
+use aoc_common::visualize::{Animate, Color, Frame, Visualize};
+use aoc_common::{Answer, ParseError, Solution};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::ops::Range;
+use tracing::debug;
+
+pub struct Day14;
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = BathroomSecurity;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.parse::<Self::Parsed>()?.with_size(PUZZLE_SIZE))
+    }
+    fn part1(ebhq: &Self::Parsed) -> Answer {
+        let mut ebhq = ebhq.clone();
+        for _ in 0..100 {
+            ebhq = ebhq.next();
+        }
+        ebhq.safety_factor().into()
+    }
+    fn part2(ebhq: &Self::Parsed) -> Answer {
+        let mut ebhq = ebhq.clone();
+        for seconds in 1..=ebhq.period() {
+            ebhq = ebhq.next();
+            if ebhq.is_christmas_tree() {
+                return seconds.into();
+            }
+        }
+        // Back where they started, the robots never show the tree
+        Answer::Unsolved
+    }
+}
+
+pub type Position = IPoint;
+type Velocity = IPoint;
+const PUZZLE_SIZE: Position = Position { x: 101, y: 103 };
+#[derive(Clone)]
+struct Robot {
+    position: Position,
+    velocity: Velocity,
+}
+impl Robot {
+    pub fn move_with_teleport(&mut self, bound: &Position) {
+        self.position += self.velocity;
+        if self.position.x < 0 {
+            self.position.x += bound.x
+        }
+        if self.position.x >= bound.x {
+            self.position.x -= bound.x
+        }
+        if self.position.y < 0 {
+            self.position.y += bound.y
+        }
+        if self.position.y >= bound.y {
+            self.position.y -= bound.y
+        }
+    }
+}
+#[derive(Clone)]
+pub struct BathroomSecurity {
+    robots: Vec<Robot>,
+    size: Option<Position>,
+}
+
+#[derive(Debug)]
+struct Quadrant {
+    x: Range<usize>,
+    y: Range<usize>,
+}
+impl Quadrant {
+    pub fn new(x: Range<usize>, y: Range<usize>) -> Quadrant {
+        Quadrant { x, y }
+    }
+    pub fn contains(&self, pos: &Position) -> bool {
+        self.x.contains(&(pos.x as usize)) && self.y.contains(&(pos.y as usize))
+    }
+}
+
+impl BathroomSecurity {
+    pub fn with_size(mut self, size: Position) -> BathroomSecurity {
+        self.size = Some(size);
+        self
+    }
+    /// Seconds after which every robot is back at its starting position.
+    pub fn period(&self) -> usize {
+        (self.size.unwrap().x * self.size.unwrap().y) as usize
+    }
+    pub fn safety_factor(&self) -> usize {
+        let max_x = self.size.unwrap().x as usize;
+        let max_y = self.size.unwrap().y as usize;
+        let quadrants = [
+            Quadrant::new(0..(max_x / 2), 0..(max_y / 2)),
+            Quadrant::new((max_x / 2 + 1)..max_x, 0..(max_y / 2)),
+            Quadrant::new(0..(max_x / 2), (max_y / 2 + 1)..max_y),
+            Quadrant::new((max_x / 2 + 1)..max_x, (max_y / 2 + 1)..max_y),
+        ];
+        debug!(?quadrants);
+        quadrants
+            .iter()
+            .map(|quadrant| {
+                self.robots
+                    .iter()
+                    .filter(|robot| quadrant.contains(&robot.position))
+                    .count()
+            })
+            .product()
+    }
+
+    pub fn is_christmas_tree(&self) -> bool {
+        // Find friendly robot
+        let map_quadrant = Quadrant::new(
+            0..self.size.unwrap().x as usize,
+            0..self.size.unwrap().y as usize,
+        );
+        for robot in &self.robots {
+            let neighbors = std::iter::once(Velocity::default())
+                .chain(Compass::ALL.map(Velocity::from))
+            .map(|vel| robot.position + vel)
+            .filter(|pos| map_quadrant.contains(pos))
+            .collect::<Vec<_>>();
+            if neighbors.len() < 9 {
+                break;
+            }
+            if neighbors.iter().all(|neighbor| {
+                self.robots
+                    .iter()
+                    .find(|robot| robot.position == *neighbor)
+                    .is_some()
+            }) {
+                return true;
+            }
+        }
+        false
+    }
+    pub fn next(mut self) -> Self {
+        let bound = self.size.unwrap();
+        for robot in &mut self.robots {
+            robot.move_with_teleport(&bound)
+        }
+        self
+    }
+
+    fn robot_counts(&self) -> HashMap<Position, i32> {
+        let mut robot_counts = HashMap::new();
+        for robot in self.robots.iter() {
+            *robot_counts.entry(robot.position).or_insert(0) += 1;
+        }
+        robot_counts
+    }
+}
+impl Display for BathroomSecurity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let robot_counts = self.robot_counts();
+        for y in 0..self.size.unwrap().y {
+            for x in 0..self.size.unwrap().x {
+                match robot_counts.get(&Position { x, y }) {
+                    None => write!(f, ".")?,
+                    Some(count) => write!(f, "{}", count)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+impl Animate for Day14 {
+    fn animate(ebhq: BathroomSecurity) -> Box<dyn Visualize> {
+        Box::new(Seconds { ebhq, seconds: 0 })
+    }
+}
+/// The robots second by second, until they show the christmas tree or are back where they started.
+struct Seconds {
+    ebhq: BathroomSecurity,
+    seconds: usize,
+}
+impl Visualize for Seconds {
+    fn frame(&self) -> Frame {
+        let caption = format!(
+            "After {} seconds, safety factor {}",
+            self.seconds,
+            self.ebhq.safety_factor()
+        );
+        let mut frame = Frame::from_display(&self.ebhq).with_caption(caption);
+        frame.paint("123456789", Color::Green);
+        frame
+    }
+    fn step(&mut self) -> bool {
+        if self.ebhq.is_christmas_tree() || self.seconds >= self.ebhq.period() {
+            return false;
+        }
+        self.ebhq = self.ebhq.clone().next();
+        self.seconds += 1;
+        true
+    }
+}
+fn parse_point(line: Line, token: &str, prefix: &str) -> Result<IPoint, ParseError> {
+    let coordinates = line.strip_prefix(token, prefix)?;
+    let (x, y) = coordinates
+        .split_once(',')
+        .ok_or_else(|| line.error(coordinates, "two comma separated numbers"))?;
+    Ok(IPoint::new(line.parse(x, "a number")?, line.parse(y, "a number")?))
+}
+impl Robot {
+    fn parse(line: Line) -> Result<Self, ParseError> {
+        let (position, velocity) = line.split_once(" ", "a robot like p=0,4 v=3,-3")?;
+        Ok(Robot {
+            position: parse_point(line, position, "p=")?,
+            velocity: parse_point(line, velocity, "v=")?,
+        })
+    }
+}
+impl FromStr for BathroomSecurity {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Ok(BathroomSecurity {
+            robots: parse_lines(input, Robot::parse)?,
+            size: None,
+        })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use crate::{BathroomSecurity, Day14, PUZZLE_SIZE, Position, Seconds};
+    use aoc_common::{Answer, Solution, parse_input};
+    use aoc_common::visualize::Visualize;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_part1() {
+        let ebhq: BathroomSecurity = parse_input!("example-input.txt").unwrap();
+        let mut ebhq = ebhq.with_size(Position { x: 11, y: 7 });
+        for _ in 0..100 {
+            ebhq = ebhq.next();
+        }
+        assert_eq!(ebhq.safety_factor(), 12);
+    }
+    #[test]
+    fn test_part2() {
+        let ebhq: BathroomSecurity = parse_input!("example-input.txt").unwrap();
+        assert_eq!(Day14::part2(&ebhq.with_size(PUZZLE_SIZE)), Answer::Unsolved);
+    }
+    #[test]
+    fn test_visualize() {
+        let ebhq: BathroomSecurity = "p=2,4 v=2,-3".parse().unwrap();
+        let mut seconds = Seconds {
+            ebhq: ebhq.with_size(Position { x: 11, y: 7 }),
+            seconds: 0,
+        };
+        assert!(seconds.step());
+        let frame = seconds.frame();
+        assert_eq!(frame.caption, "After 1 seconds, safety factor 0");
+        assert_eq!(frame.to_string().lines().nth(2), Some("....1......"));
+        while seconds.step() {}
+        assert_eq!(seconds.seconds, 77);
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = "p=0,4 v=3,-3\np=6,3 v=-1,x".parse::<BathroomSecurity>();
+        assert_eq!(error.err().map(|e| (e.line, e.column)), Some((2, 12)));
+        let error = "p=0,4 v=3".parse::<BathroomSecurity>().err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 9: expected two comma separated numbers, found \"3\""
+        );
+    }
+
+    /// Robot as `(px, py, vx, vy)`.
+    type RobotSpec = (isize, isize, isize, isize);
+    /// Odd sized space and robots slower than a lap per second.
+    fn arb_space() -> impl Strategy<Value = ((isize, isize), Vec<RobotSpec>)> {
+        (1..12isize, 1..12isize).prop_flat_map(|(half_x, half_y)| {
+            let (w, h) = (half_x * 2 + 1, half_y * 2 + 1);
+            let robot = (0..w, 0..h, -w + 1..w, -h + 1..h);
+            (Just((w, h)), prop::collection::vec(robot, 0..30))
+        })
+    }
+    fn brute_safety_factor((w, h): (isize, isize), robots: &[RobotSpec]) -> usize {
+        let mut quadrants = [0; 4];
+        for &(px, py, vx, vy) in robots {
+            let (x, y) = ((px + vx * 100).rem_euclid(w), (py + vy * 100).rem_euclid(h));
+            if x != w / 2 && y != h / 2 {
+                quadrants[usize::from(x > w / 2) + 2 * usize::from(y > h / 2)] += 1;
+            }
+        }
+        quadrants.iter().product()
+    }
+    proptest! {
+        #[test]
+        fn prop_matches_brute_force((size, robots) in arb_space()) {
+            let input: String = robots
+                .iter()
+                .map(|(px, py, vx, vy)| format!("p={px},{py} v={vx},{vy}\n"))
+                .collect();
+            let ebhq: BathroomSecurity = input.parse().unwrap();
+            let mut ebhq = ebhq.with_size(Position { x: size.0, y: size.1 });
+            for _ in 0..100 {
+                ebhq = ebhq.next();
+            }
+            prop_assert_eq!(ebhq.safety_factor(), brute_safety_factor(size, &robots));
+        }
+    }
+}
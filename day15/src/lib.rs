@@ -0,0 +1,353 @@
+use aoc_common::geometry::Direction;
+use aoc_common::geometry::UPoint;
+use aoc_common::parse::Lines;
+use aoc_common::visualize::{Animate, Color, Frame, Visualize};
+use aoc_common::{Answer, Grid, ParseError, Solution};
+use std::collections::VecDeque;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub struct Day15;
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = Warehouse;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(warehouse: &Self::Parsed) -> Answer {
+        let mut warehouse = warehouse.clone();
+        while warehouse.move_robot().is_some() {}
+        warehouse.gps_sum().into()
+    }
+    fn part2(warehouse: &Self::Parsed) -> Answer {
+        let mut wide_warehouse = WideWarehouse::from(warehouse);
+        while wide_warehouse.move_robot().is_some() {}
+        wide_warehouse.gps_sum().into()
+    }
+}
+
+pub type Position = UPoint;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Space {
+    Empty,
+    Wall,
+    Obstacle,
+    Robot,
+}
+impl Display for Space {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            Space::Empty => write!(f, "."),
+            Space::Wall => write!(f, "#"),
+            Space::Obstacle => write!(f, "O"),
+            Space::Robot => write!(f, "@"),
+        }
+    }
+}
+#[derive(Debug, Clone)]
+struct Robot {
+    position: Position,
+    moves: VecDeque<Direction>,
+}
+impl Robot {
+    fn parse(lines: Lines, position: Position) -> Result<Self, ParseError> {
+        let mut moves = VecDeque::new();
+        for line in lines {
+            for (idx, char) in line.text.chars().enumerate() {
+                let direction = Direction::from_arrow(char)
+                    .ok_or_else(|| line.error_at_char(idx, "a move like ^, >, v or <"))?;
+                moves.push_back(direction);
+            }
+        }
+        Ok(Self { position, moves })
+    }
+}
+#[derive(Clone)]
+pub struct Warehouse {
+    spaces: Grid<Space>,
+    robot: Robot,
+}
+
+impl Warehouse {
+    fn at(&self, position: Position) -> Option<&Space> {
+        self.spaces.get(position)
+    }
+    fn set(&mut self, position: Position, space: Space) {
+        self.spaces.set(position, space);
+    }
+    fn try_move(
+        &mut self,
+        position: Position,
+        movement: Direction,
+        new_object: Space,
+    ) -> Option<Position> {
+        let new_pos = position + movement;
+        match self.at(new_pos) {
+            Some(Space::Wall) => None,
+            Some(Space::Empty) => {
+                self.set(new_pos, new_object);
+                self.set(position, Space::Empty);
+                Some(new_pos)
+            }
+            Some(Space::Obstacle) => match self.try_move(new_pos, movement, Space::Obstacle) {
+                Some(_) => {
+                    self.set(new_pos, new_object);
+                    self.set(position, Space::Empty);
+                    Some(new_pos)
+                }
+                None => None,
+            },
+            x => unreachable!("{x:?} at {new_pos:?}"),
+        }
+    }
+    pub fn move_robot(&mut self) -> Option<Position> {
+        match self.robot.moves.pop_front() {
+            Some(movement) => {
+                if let Some(position) = self.try_move(self.robot.position, movement, Space::Robot) {
+                    self.robot.position = position;
+                }
+                Some(self.robot.position)
+            }
+            None => None,
+        }
+    }
+    pub fn gps_sum(&self) -> usize {
+        self.spaces
+            .iter()
+            .filter(|(_, space)| matches!(space, Space::Obstacle))
+            .map(|(position, _)| 100 * position.y + position.x)
+            .sum()
+    }
+}
+impl Display for Warehouse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.spaces)
+    }
+}
+impl FromStr for Warehouse {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Lines::new(input);
+        let spaces = Grid::parse(
+            &mut lines,
+            |c| match c {
+                '#' => Some(Space::Wall),
+                '.' => Some(Space::Empty),
+                'O' => Some(Space::Obstacle),
+                '@' => Some(Space::Robot),
+                _ => None,
+            },
+            "a warehouse space",
+        )?;
+        let position = spaces
+            .position(|space| matches!(space, Space::Robot))
+            .ok_or_else(|| {
+                ParseError::end_of_input(lines.number(), 1, "a robot '@' in the warehouse")
+            })?;
+        Ok(Self {
+            spaces,
+            robot: Robot::parse(lines, position)?,
+        })
+    }
+}
+impl Animate for Day15 {
+    fn animate(warehouse: Warehouse) -> Box<dyn Visualize> {
+        Box::new(warehouse)
+    }
+}
+/// One robot move per step.
+impl Visualize for Warehouse {
+    fn frame(&self) -> Frame {
+        let next = match self.robot.moves.front() {
+            Some(movement) => format!("next {movement}"),
+            None => "done".to_string(),
+        };
+        let mut frame = Frame::from_display(self).with_caption(format!(
+            "{} moves left, {next}, GPS sum {}",
+            self.robot.moves.len(),
+            self.gps_sum()
+        ));
+        frame.paint("#", Color::Gray);
+        frame.paint("O", Color::Yellow);
+        frame.paint("@", Color::Red);
+        frame
+    }
+    fn step(&mut self) -> bool {
+        self.move_robot().is_some()
+    }
+}
+struct WideWarehouse {
+    map: Grid<char>,
+    robot: Robot,
+}
+#[derive(Debug)]
+struct WallError {}
+impl WideWarehouse {
+    fn at(&self, position: Position) -> Option<char> {
+        self.map.get(position).copied()
+    }
+    fn set(&mut self, position: Position, char: char) {
+        self.map.set(position, char);
+    }
+
+    fn try_move(
+        &mut self,
+        position: Position,
+        movement: Direction,
+    ) -> Result<Vec<Position>, WallError> {
+        let new_pos = position + movement;
+        match (self.at(new_pos), movement) {
+            (Some('#'), _) => Err(WallError {}),
+            (Some('.'), _) => Ok(vec![position]),
+            (Some('[') | Some(']'), Direction::Right | Direction::Left) => {
+                match self.try_move(new_pos, movement) {
+                    Ok(mut vec) => {
+                        vec.push(position);
+                        Ok(vec)
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            (Some('['), Direction::Up | Direction::Down) => {
+                let mut vec = Vec::new();
+                vec.append(self.try_move(new_pos, movement)?.as_mut());
+                vec.append(
+                    self.try_move(new_pos + Direction::Right, movement)?
+                        .as_mut(),
+                );
+                vec.push(position);
+                Ok(vec)
+            }
+
+            (Some(']'), Direction::Up | Direction::Down) => {
+                let mut vec = Vec::new();
+                vec.append(self.try_move(new_pos, movement)?.as_mut());
+                vec.append(self.try_move(new_pos + Direction::Left, movement)?.as_mut());
+                vec.push(position);
+                Ok(vec)
+            }
+            x => unreachable!("{x:?} at {new_pos:?}"),
+        }
+    }
+    pub fn move_robot(&mut self) -> Option<Position> {
+        match self.robot.moves.pop_front() {
+            Some(movement) => {
+                match self.try_move(self.robot.position, movement) {
+                    Ok(vec) => {
+                        let old_pos = vec
+                            .into_iter()
+                            .map(|pos| (pos, self.at(pos).unwrap()))
+                            .collect::<Vec<_>>();
+                        // clear old pos
+                        for (old_pos, _) in old_pos.iter() {
+                            self.set(*old_pos, '.');
+                        }
+                        // put objects into new positions
+                        for (new_pos, new_char) in old_pos
+                            .into_iter()
+                            .map(|(pos, char)| (pos + movement, char))
+                        {
+                            self.set(new_pos, new_char);
+                        }
+                        // update robot location
+                        self.where_robot();
+                        Some(self.robot.position)
+                    }
+                    Err(_) => Some(self.robot.position),
+                }
+            }
+            None => None,
+        }
+    }
+    fn where_robot(&mut self) {
+        self.robot.position = self.map.position(|&space| space == '@').unwrap();
+        // x: 11 y:4
+        assert_eq!(self.at(self.robot.position).unwrap(), '@');
+    }
+    pub fn gps_sum(&self) -> usize {
+        let mut sum = 0;
+        for (Position { x, y }, &char) in self.map.iter() {
+            match char {
+                /* Treat right border as edge of map
+                 '[' if x < self.map.width()/2 => sum += 100 * min(y,self.map.height()-y-1) + x,
+                 ']' if x > self.map.width()/2 => sum += 100 * min(y,self.map.height()-y-1) + (self.map.width()-x-1),
+                */
+                '[' => sum += 100 * y + x,
+                _ => continue,
+            }
+        }
+        sum
+    }
+}
+impl From<&Warehouse> for WideWarehouse {
+    fn from(warehouse: &Warehouse) -> Self {
+        let mut new_robot = warehouse.robot.clone();
+        new_robot.position.x = warehouse.robot.position.x * 2;
+        Self {
+            map: Grid::from_rows(warehouse.spaces.rows().map(|row| {
+                row.iter()
+                    .flat_map(|space| match space {
+                        Space::Wall => ['#', '#'],
+                        Space::Obstacle => ['[', ']'],
+                        Space::Empty => ['.', '.'],
+                        Space::Robot => ['@', '.'],
+                    })
+                    .collect()
+            })),
+            robot: new_robot,
+        }
+    }
+}
+impl Display for WideWarehouse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+#[cfg(test)]
+mod tests {
+
+    use crate::{Day15, Warehouse};
+    use aoc_common::visualize::Visualize;
+
+    // wide_1 is the puzzle's small wide example; its answer sums the GPS of the boxes in the
+    // final layout the puzzle shows: 105 + 207 + 306.
+    aoc_common::example_tests!(
+        Day15,
+        "examples.txt",
+        [
+            small, larger, wide_1, wide_2, wide_3, wide_4, wide_5, wide_6
+        ]
+    );
+
+    #[test]
+    fn test_parse_error() {
+        let error = "#####\n#.@x#\n#####\n\n<^"
+            .parse::<Warehouse>()
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        let error = "#####\n#.@.#\n#####\n\n<^\nv>!"
+            .parse::<Warehouse>()
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (6, 3));
+        let error = "#####\n#...#\n#####".parse::<Warehouse>().err().unwrap();
+        assert_eq!(error.found, None);
+    }
+    #[test]
+    fn test_visualize() {
+        let mut warehouse = "#####\n#@O.#\n#####\n\n>>".parse::<Warehouse>().unwrap();
+        assert_eq!(
+            warehouse.frame().caption,
+            "2 moves left, next >, GPS sum 102"
+        );
+        while warehouse.step() {}
+        let frame = warehouse.frame();
+        assert_eq!(
+            frame.to_string(),
+            "0 moves left, done, GPS sum 103\n#####\n#.@O#\n#####\n"
+        );
+    }
+}
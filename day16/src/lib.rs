@@ -0,0 +1,255 @@
+use aoc_common::geometry::Direction::{self, Right};
+use aoc_common::geometry::UPoint;
+use aoc_common::parse::Lines;
+use aoc_common::search::{dijkstra, dijkstra_all, Path};
+use aoc_common::visualize::{Animate, Color, Frame, Visualize};
+use aoc_common::{Answer, Grid, ParseError, Solution};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub struct Day16;
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = Maze;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(maze: &Self::Parsed) -> Answer {
+        maze.cheapest_path().map_or(Answer::Unsolved, Answer::from)
+    }
+    fn part2(maze: &Self::Parsed) -> Answer {
+        maze.best_seats().map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+type Position = UPoint;
+
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+enum Tile {
+    Start,
+    End,
+    Empty,
+    Wall,
+}
+impl Tile {
+    fn from_char(c: char) -> Option<Tile> {
+        match c {
+            'S' => Some(Tile::Start),
+            'E' => Some(Tile::End),
+            '.' => Some(Tile::Empty),
+            '#' => Some(Tile::Wall),
+            _ => None,
+        }
+    }
+}
+impl From<Tile> for char {
+    fn from(val: Tile) -> Self {
+        match val {
+            Tile::Start => 'S',
+            Tile::End => 'E',
+            Tile::Empty => '.',
+            Tile::Wall => '#',
+        }
+    }
+}
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", Into::<char>::into(*self))
+    }
+}
+pub struct Maze {
+    map: Grid<Tile>,
+    start: Position,
+    end: Position,
+}
+impl Maze {
+    fn at(&self, index: Position) -> Tile {
+        self.map[index]
+    }
+    fn next_nodes(&self, current: Raindeer) -> Vec<(Raindeer, usize)> {
+        [(current.forward(), 1), (current.left(), 1001), (current.right(), 1001)]
+            .into_iter()
+            .filter(|(next, _)| matches!(self.at(next.pos), Tile::Empty | Tile::End))
+            .collect()
+    }
+    fn start(&self) -> Raindeer {
+        Raindeer {
+            pos: self.start,
+            orientation: Right,
+        }
+    }
+    fn cheapest_route(&self) -> Option<Path<Raindeer, usize>> {
+        dijkstra(
+            self.start(),
+            |&raindeer| self.next_nodes(raindeer),
+            |raindeer| raindeer.pos == self.end,
+        )
+    }
+    pub fn cheapest_path(&self) -> Option<usize> {
+        self.cheapest_route().map(|path| path.cost)
+    }
+    /// Tiles on any cheapest path, `None` when the end tile can't be reached.
+    pub fn best_seats(&self) -> Option<usize> {
+        // every optimal predecessor of the cheapest ways into the end tile
+        let paths = dijkstra_all(self.start(), |&raindeer| self.next_nodes(raindeer));
+        let best_cost = paths
+            .reached()
+            .filter(|(raindeer, _)| raindeer.pos == self.end)
+            .map(|(_, cost)| cost)
+            .min()?;
+        let ends = paths
+            .reached()
+            .filter(|&(raindeer, cost)| raindeer.pos == self.end && cost == best_cost)
+            .map(|(raindeer, _)| *raindeer)
+            .collect::<Vec<_>>();
+        let seats = paths
+            .nodes_on_paths(ends)
+            .into_iter()
+            .map(|raindeer| raindeer.pos)
+            .collect::<HashSet<_>>();
+        Some(seats.len())
+    }
+}
+impl Animate for Day16 {
+    fn animate(maze: Maze) -> Box<dyn Visualize> {
+        let route = maze.cheapest_route();
+        Box::new(Route {
+            maze,
+            route,
+            shown: 1,
+        })
+    }
+}
+/// The cheapest route through the maze, revealed one tile per step.
+struct Route {
+    maze: Maze,
+    route: Option<Path<Raindeer, usize>>,
+    shown: usize,
+}
+impl Visualize for Route {
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::from_display(&self.maze);
+        frame.paint("#", Color::Gray);
+        frame.paint("SE", Color::Cyan);
+        let Some(route) = &self.route else {
+            return frame.with_caption("No route to the end tile");
+        };
+        for raindeer in &route.nodes[1..self.shown] {
+            let arrow = raindeer.orientation.to_string().chars().next().unwrap();
+            frame.set(raindeer.pos, arrow, Color::Green);
+        }
+        frame.with_caption(format!(
+            "Tile {}/{}, score {}",
+            self.shown,
+            route.nodes.len(),
+            route.cost
+        ))
+    }
+    fn step(&mut self) -> bool {
+        let tiles = self.route.as_ref().map_or(0, |route| route.nodes.len());
+        if self.shown >= tiles {
+            return false;
+        }
+        self.shown += 1;
+        true
+    }
+}
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
+struct Raindeer {
+    pos: Position,
+    orientation: Direction,
+}
+impl Raindeer {
+    pub fn forward(&self) -> Self {
+        Self {
+            pos: self.pos + self.orientation,
+            orientation: self.orientation,
+        }
+    }
+    pub fn left(&self) -> Self {
+        Self {
+            pos: self.pos + self.orientation.rotate_counter_clockwise(),
+            orientation: self.orientation.rotate_counter_clockwise(),
+        }
+    }
+    pub fn right(&self) -> Self {
+        Self {
+            pos: self.pos + self.orientation.rotate_clockwise(),
+            orientation: self.orientation.rotate_clockwise(),
+        }
+    }
+}
+
+impl FromStr for Maze {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Lines::new(input);
+        let map = Grid::parse(&mut lines, Tile::from_char, "a maze tile")?;
+        let find = |tile: Tile, expected: &str| {
+            map.position(|&t| t == tile)
+                .ok_or_else(|| ParseError::end_of_input(lines.number() + 1, 1, expected))
+        };
+        Ok(Self {
+            start: find(Tile::Start, "a start tile 'S'")?,
+            end: find(Tile::End, "an end tile 'E'")?,
+            map,
+        })
+    }
+}
+
+impl Display for Maze {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day16, Maze, Position};
+    use aoc_common::geometry::Direction::{Down, Left, Right, Up};
+    use aoc_common::visualize::Animate;
+    use aoc_common::{Answer, Solution};
+
+    aoc_common::example_tests!(Day16, "examples.txt", [single_path, branching]);
+
+    #[test]
+    fn test_helpers() {
+        let mover = Up;
+        assert_eq!(mover.rotate_clockwise(), Right);
+        assert_eq!(mover.rotate_counter_clockwise(), Left);
+
+        let pos = Position { x: 1, y: 1 };
+        assert_eq!(Position { x: 0, y: 1 }, pos + Left);
+        assert_eq!(Position { x: 2, y: 1 }, pos + Right);
+        assert_eq!(Position { x: 1, y: 0 }, pos + Up);
+        assert_eq!(Position { x: 1, y: 2 }, pos + Down);
+    }
+    #[test]
+    fn test_visualize() {
+        let maze: Maze = "#####\n#..E#\n#S###\n#####".parse().unwrap();
+        let mut route = Day16::animate(maze);
+        while route.step() {}
+        assert_eq!(
+            route.frame().to_string(),
+            "Tile 4/4, score 2003\n#####\n#^>>#\n#S###\n#####\n"
+        );
+    }
+    #[test]
+    fn test_unreachable_end() {
+        let maze: Maze = "#####\n#S#E#\n#####".parse().unwrap();
+        assert_eq!(Day16::part1(&maze), Answer::Unsolved);
+        assert_eq!(Day16::part2(&maze), Answer::Unsolved);
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = "####\n#SE#\n#.x#\n####".parse::<Maze>().err().unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+        let error = "####\n#S.#\n####".parse::<Maze>().err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: expected an end tile 'E', found end of input"
+        );
+    }
+}
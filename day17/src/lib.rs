@@ -0,0 +1,275 @@
+use crate::Instruction::*;
+use aoc_common::{Answer, ParseError, Solution};
+use aoc_common::parse::Lines;
+use std::collections::VecDeque;
+use std::ops::BitXor;
+use std::str::FromStr;
+use tracing::{instrument, trace};
+
+pub struct Day17;
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = ChronospatialComputer;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(computer: &Self::Parsed) -> Answer {
+        computer.clone().execute_to_string().into()
+    }
+    fn part2(computer: &Self::Parsed) -> Answer {
+        match computer.clone().find_a() {
+            Some(a) => a.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+enum Instruction {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+impl TryFrom<u8> for Instruction {
+    type Error = ProgramHaltedError;
+
+    fn try_from(c: u8) -> Result<Instruction, ProgramHaltedError> {
+        Ok(match c {
+            0 => Adv,
+            1 => Bxl,
+            2 => Bst,
+            3 => Jnz,
+            4 => Bxc,
+            5 => Out,
+            6 => Bdv,
+            7 => Cdv,
+            _ => return Err(ProgramHaltedError{msg: "Invalid instruction {c}".to_string()})
+        })
+    }
+}
+#[derive(Clone)]
+struct Registers{
+    a: u128,
+    b: u128,
+    c: u128,
+}
+#[derive(Clone)]
+pub struct ChronospatialComputer {
+    program: Vec<u8>,
+    registers: Registers,
+    instruction: usize,
+}
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct ProgramHaltedError{
+    msg: String
+}
+
+impl ChronospatialComputer {
+    fn combo_operand(&self, operand: u8) -> Result<u128, ProgramHaltedError> {
+        match operand {
+            0..=3 => Ok(operand as u128),
+            4 => Ok(self.registers.a),
+            5 => Ok(self.registers.b),
+            6 => Ok(self.registers.c),
+            _ => Err(ProgramHaltedError{msg: "Invalid operand".to_string()}),
+        }
+    }
+    pub fn step(&mut self) -> Result<Option<u8>, ProgramHaltedError> {
+        let instr = match self.program.get(self.instruction) {
+            None => return Err(ProgramHaltedError{msg: "Attempt to read past program".to_string()}),
+            Some(instr) => Instruction::try_from(*instr)?,
+        };
+        let literal_operand = match self.program.get(self.instruction + 1) {
+            None => return Err(ProgramHaltedError{msg: "Attempt to read past program".to_string()}),
+            Some(instr) => *instr,
+        };
+        self.instruction += 2;
+        match instr {
+            Adv => {
+                self.registers.a /= 2_u128.pow(self.combo_operand(literal_operand)? as u32);
+                Ok(None)
+            }
+            Bxl => {
+                self.registers.b = self.registers.b.bitxor(literal_operand as u128);
+                Ok(None)
+            }
+            Bst => {
+                self.registers.b = self.combo_operand(literal_operand)? % 8;
+                Ok(None)
+            }
+            Jnz => {
+                match self.registers.a {
+                    0 => Ok(None),
+                    _ => {
+                        self.instruction = literal_operand as usize;
+                        Ok(None)
+                    }
+                }
+            }
+            Bxc => {
+                self.registers.b = self.registers.b.bitxor(self.registers.c);
+                Ok(None)
+            }
+            Out => {
+                Ok(Some(self.combo_operand(literal_operand)? as u8 % 8))
+            }
+            Bdv => {
+                self.registers.b = self.registers.a / 2_u128.pow(self.combo_operand(literal_operand)? as u32);
+                Ok(None)
+            }
+            Cdv => {
+                self.registers.c = self.registers.a / 2_u128.pow(self.combo_operand(literal_operand)? as u32);
+                Ok(None)
+            }
+        }
+    }
+    fn execute(&mut self) -> Vec<u8> {
+        let mut output = Vec::new();
+        while let Ok(sth) = self.step(){
+            match sth{
+                None => {}
+                Some(x) => output.push(x)
+            }
+        }
+        output
+    }
+    pub fn execute_to_string(&mut self) -> String {
+        self.execute().into_iter().map(|x|format!("{x}")).collect::<Vec<_>>().join(",")
+    }
+
+    #[instrument(skip_all)]
+    pub fn find_a(&mut self) -> Option<u128> {
+        let mut to_visit = VecDeque::from([(self.program.len(),0)]);
+        while let Some((pos,a)) = to_visit.pop_front() {
+            for i in 0..8 {
+                let new_a = a * 8 + i;
+                self.registers = Registers{
+                    a: new_a,
+                    b: 0,
+                    c: 0,
+                };
+                self.instruction = 0;
+                let o = self.execute();
+                trace!(a = new_a, a_hex = format!("{new_a:x}"), output = ?o, "checking a");
+                if o[..] == self.program[pos - 1..] {
+                    to_visit.push_back((pos-1, new_a));
+                    if o.len() == self.program.len() {
+                        return Some(new_a);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+fn parse_register(lines: &mut Lines, name: char) -> Result<u128, ParseError> {
+    let expected = format!("register {name} like \"Register {name}: 729\"");
+    let line = lines.expect(&expected)?;
+    let value = line.strip_prefix(line.text, &format!("Register {name}: "))?;
+    line.parse(value, "a register value")
+}
+
+impl FromStr for ChronospatialComputer {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = Lines::new(s);
+        let registers = Registers {
+            a: parse_register(&mut lines, 'a')?,
+            b: parse_register(&mut lines, 'b')?,
+            c: parse_register(&mut lines, 'c')?,
+        };
+        let line = lines
+            .find(|line| !line.text.is_empty())
+            .ok_or_else(|| ParseError::end_of_input(lines.number() + 1, 1, "the program"))?;
+        let program = line.strip_prefix(line.text, "Program: ")?;
+        Ok(Self {
+            program: program
+                .split(',')
+                .map(|opcode| line.parse(opcode, "a 3-bit number"))
+                .collect::<Result<_, _>>()?,
+            registers,
+            instruction: 0,
+        })
+    }
+}
+
+/// Runs the program with every value of register A below 8^len, the first one printing the program
+/// is the answer.
+#[cfg(any(test, feature = "reference"))]
+pub mod reference {
+    use crate::{ChronospatialComputer, Day17};
+    use aoc_common::Answer;
+    use aoc_common::reference::Reference;
+    use proptest::prelude::*;
+
+    impl Reference for Day17 {
+        /// Loops shifting A by 3 bits and printing a combo operand, as the puzzle programs do, but
+        /// short enough to try every A.
+        fn inputs() -> BoxedStrategy<String> {
+            (0..1000u32, 0..=4u8, any::<bool>())
+                .prop_map(|(a, printed, print_first)| {
+                    let program = match print_first {
+                        true => format!("5,{printed},0,3,3,0"),
+                        false => format!("0,3,5,{printed},3,0"),
+                    };
+                    let registers = format!("Register a: {a}\nRegister b: 0\nRegister c: 0\n");
+                    format!("{registers}\nProgram: {program}\n")
+                })
+                .boxed()
+        }
+        fn reference_part1(_: &ChronospatialComputer) -> Option<Answer> {
+            None
+        }
+        fn reference_part2(computer: &ChronospatialComputer) -> Option<Answer> {
+            let a = (0..8u128.pow(computer.program.len() as u32)).find(|&a| {
+                let mut computer = computer.clone();
+                computer.registers.a = a;
+                computer.execute() == computer.program
+            });
+            // Without one the puzzle has no answer to compare with
+            a.map(Answer::from)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChronospatialComputer, Day17};
+    use aoc_common::{Answer, Solution, parse_input};
+    use aoc_common::reference::verify;
+
+    #[test]
+    fn test_part1() {
+        let mut chronospatial_computer: ChronospatialComputer =
+            parse_input!("example-input.txt").unwrap();
+        let res = chronospatial_computer.execute_to_string();
+        assert_eq!(res, "4,6,3,5,6,3,5,2,1,0");
+    }
+    #[test]
+    fn test_part2() {
+        let chronospatial_computer: ChronospatialComputer =
+            parse_input!("example-input.txt").unwrap();
+        // The example doesn't print itself for any A
+        assert_eq!(Day17::part2(&chronospatial_computer), Answer::Unsolved);
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = "Register a: 729\nRegister b: x\n".parse::<ChronospatialComputer>();
+        assert_eq!(error.err().map(|e| (e.line, e.column)), Some((2, 13)));
+        let error = "Register a: 1\nRegister b: 0\nRegister c: 0\n\nProgram: 0,1,z"
+            .parse::<ChronospatialComputer>();
+        assert_eq!(error.err().map(|e| (e.line, e.column)), Some((5, 14)));
+    }
+    #[test]
+    fn test_reference() {
+        assert_eq!(verify::<Day17>(8), Ok(()));
+    }
+}
@@ -0,0 +1,328 @@
+use aoc_common::geometry::UPoint;
+use aoc_common::parse::{Line, parse_lines};
+use aoc_common::search::{astar, Path};
+use aoc_common::visualize::{Animate, Color, Frame, Visualize};
+use aoc_common::{Answer, Grid, ParseError, Solution};
+use std::collections::VecDeque;
+use std::fmt::{Display};
+use std::str::FromStr;
+use tracing::{debug, instrument};
+
+pub struct Day18;
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = RAMRun;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(ram_run: &Self::Parsed) -> Answer {
+        let mut ram_run = ram_run.clone();
+        ram_run.fall_bytes(ram_run.simulated_bytes());
+        match ram_run.cheapest_path_from(ram_run.start) {
+            Some(path) => path.steps().into(),
+            None => Answer::Unsolved,
+        }
+    }
+    fn part2(ram_run: &Self::Parsed) -> Answer {
+        match first_blocking_byte(ram_run) {
+            Some(byte) => byte.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+/// The byte cutting the exit off, `None` when it's cut off before any byte falls or never is.
+#[instrument(skip_all)]
+fn first_blocking_byte(ram_run: &RAMRun) -> Option<Position> {
+    let blocked = |fallen| {
+        let mut ram_run = ram_run.clone();
+        ram_run.fall_bytes(fallen);
+        ram_run.cheapest_path_from(ram_run.start).is_none()
+    };
+    let bytes = &ram_run.falling_bytes.bytes;
+    let (mut left, mut right) = (0usize, bytes.len());
+    if blocked(left) || !blocked(right) {
+        return None;
+    }
+    // Open after `left` bytes, cut off after `right`
+    while right - left > 1 {
+        let test = left.midpoint(right);
+        match blocked(test) {
+            false => left = test,
+            true => right = test,
+        }
+        debug!(left, right, "bisection");
+    }
+    Some(bytes[right - 1])
+}
+pub type Position = UPoint;
+fn parse_position(line: Line) -> Result<Position, ParseError> {
+    let (x, y) = line.split_once(",", "a byte position like 5,4")?;
+    Ok(Position {
+        x: line.parse(x, "a column")?,
+        y: line.parse(y, "a row")?,
+    })
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Tile {
+    Safe,
+    Corrupted,
+}
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Safe => write!(f, "."),
+            Tile::Corrupted => write!(f, "#"),
+        }
+    }
+}
+#[derive(Debug, Clone)]
+struct MemoryCorruptor {
+    bytes: VecDeque<Position>,
+}
+impl MemoryCorruptor {}
+impl FromStr for MemoryCorruptor {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            bytes: parse_lines(input, parse_position)?.into(),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RAMRun {
+    falling_bytes: MemoryCorruptor,
+    memory: Grid<Tile>,
+    start: Position,
+    end: Position,
+}
+impl RAMRun {
+    fn at(&self, pos: Position) -> Tile {
+        self.memory[pos]
+    }
+    pub fn fall_byte(&mut self) -> Option<Position> {
+        if let Some(pos) = self.falling_bytes.bytes.pop_front() {
+            self.memory.set(pos, Tile::Corrupted);
+            return Some(pos);
+        }
+        None
+    }
+    /// Bytes fallen after the first kilobyte, or 12 for the small example memory space
+    pub fn simulated_bytes(&self) -> usize {
+        if self.end.x > 6 { 1024 } else { 12 }
+    }
+    pub fn fall_bytes(&mut self, count: usize) {
+        for _ in 0..count {
+            if self.fall_byte().is_none() {
+                break;
+            }
+        }
+    }
+
+    fn next_nodes(&self, pos: Position) -> Vec<Position> {
+        self.memory
+            .neighbours4(pos)
+            .filter(|&cell| self.memory[cell] == Tile::Safe)
+            .collect()
+    }
+    fn cheapest_path_from(&self, start: Position) -> Option<Path<Position, usize>> {
+        astar(
+            start,
+            |&pos| self.next_nodes(pos).into_iter().map(|next| (next, 1)),
+            |pos| pos.manhattan(self.end),
+            |&pos| pos == self.end,
+        )
+    }
+    fn print_with_marked_path(&self, path: &Path<Position, usize>) -> String {
+        let mut str = String::new();
+        for y in 0..self.memory.height() {
+            for x in 0..self.memory.width() {
+                let in_oath = path
+                    .nodes
+                    .iter()
+                    .find(|&&corrupted| corrupted == Position { x, y })
+                    .is_some();
+                match self.at(Position { x, y }) {
+                    Tile::Safe => {
+                        if in_oath {
+                            str.push('O');
+                        } else {
+                            str.push('.');
+                        }
+                    }
+                    Tile::Corrupted => {
+                        if in_oath {
+                            str.push('!');
+                        } else {
+                            str.push('#');
+                        }
+                    }
+                }
+            }
+            str.push('\n');
+        }
+        str
+    }
+}
+impl Animate for Day18 {
+    fn animate(ram_run: RAMRun) -> Box<dyn Visualize> {
+        Box::new(Falling { ram_run, fallen: 0, last: None })
+    }
+}
+/// Bytes falling one per step, with the shortest path to the exit, until it gets cut off.
+struct Falling {
+    ram_run: RAMRun,
+    fallen: usize,
+    last: Option<Position>,
+}
+impl Visualize for Falling {
+    fn frame(&self) -> Frame {
+        let fell = match self.last {
+            Some(pos) => format!("Byte {} fell at {pos}", self.fallen),
+            None => "No byte fell yet".to_string(),
+        };
+        let mut frame = match self.ram_run.cheapest_path_from(self.ram_run.start) {
+            Some(path) => Frame::from_display(&self.ram_run.print_with_marked_path(&path))
+                .with_caption(format!("{fell}, shortest path {} steps", path.steps())),
+            None => Frame::from_display(&self.ram_run)
+                .with_caption(format!("{fell}, the exit is cut off")),
+        };
+        frame.paint("#", Color::Gray);
+        frame.paint("O", Color::Green);
+        if let Some(pos) = self.last {
+            frame.set(pos, '#', Color::Red);
+        }
+        frame
+    }
+    fn step(&mut self) -> bool {
+        if self.ram_run.cheapest_path_from(self.ram_run.start).is_none() {
+            return false;
+        }
+        match self.ram_run.fall_byte() {
+            Some(pos) => {
+                self.last = Some(pos);
+                self.fallen += 1;
+                true
+            }
+            None => false,
+        }
+    }
+}
+impl FromStr for RAMRun {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let corruptor: MemoryCorruptor = input.parse()?;
+        let max = corruptor
+            .bytes
+            .iter()
+            .max_by_key(|&&pos| pos.x)
+            .ok_or_else(|| ParseError::end_of_input(1, 1, "a byte position like 5,4"))?;
+        let end = if max.x > 6 || max.y > 6 {
+            Position { x: 70, y: 70 }
+        } else {
+            Position { x: 6, y: 6 }
+        };
+        Ok(Self {
+            falling_bytes: corruptor,
+            memory: Grid::new(end.x + 1, end.y + 1, Tile::Safe),
+            start: Position { x: 0, y: 0 },
+            end,
+        })
+    }
+}
+
+impl Display for RAMRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.memory)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use crate::{first_blocking_byte, Day18, Position, RAMRun};
+    use aoc_common::visualize::Animate;
+    use aoc_common::{parse_input, Answer, Solution};
+    use proptest::prelude::*;
+    use std::collections::{HashSet, VecDeque};
+
+    #[test]
+    fn test_part1() {
+        let mut ram_run: RAMRun = parse_input!("example-input.txt").unwrap();
+        ram_run.fall_bytes(12);
+        assert_eq!(ram_run.cheapest_path_from(ram_run.start).unwrap().steps(), 22);
+    }
+    #[test]
+    fn test_part2() {
+        let ram_run: RAMRun = parse_input!("example-input.txt").unwrap();
+        assert_eq!(first_blocking_byte(&ram_run), Some(Position { x: 6, y: 1 }));
+    }
+    #[test]
+    fn test_cut_off() {
+        let ram_run: RAMRun = "0,1\n1,0\n".parse().unwrap();
+        assert_eq!(Day18::part1(&ram_run), Answer::Unsolved);
+        assert_eq!(Day18::part2(&ram_run), Answer::from(Position { x: 1, y: 0 }));
+        let ram_run: RAMRun = "0,1\n".parse().unwrap();
+        assert_eq!(Day18::part2(&ram_run), Answer::Unsolved);
+    }
+    #[test]
+    fn test_visualize() {
+        let ram_run: RAMRun = parse_input!("example-input.txt").unwrap();
+        let mut falling = Day18::animate(ram_run);
+        assert_eq!(falling.frame().caption, "No byte fell yet, shortest path 12 steps");
+        while falling.step() {}
+        assert_eq!(falling.frame().caption, "Byte 21 fell at 6,1, the exit is cut off");
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = "5,4\n4;2".parse::<RAMRun>().err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        let error = "5,4\n-4,2".parse::<RAMRun>().err().unwrap();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a column, found \"-4\"");
+        assert!("".parse::<RAMRun>().is_err());
+    }
+
+    /// Some of the cells of the small memory space but the corners, in falling order.
+    fn arb_falling_bytes() -> impl Strategy<Value = Vec<(usize, usize)>> {
+        let cells: Vec<_> = (0..7)
+            .flat_map(|y| (0..7).map(move |x| (x, y)))
+            .filter(|&cell| cell != (0, 0) && cell != (6, 6))
+            .collect();
+        let count = cells.len();
+        (Just(cells).prop_shuffle(), 1..=count).prop_map(|(mut cells, fallen)| {
+            cells.truncate(fallen);
+            cells
+        })
+    }
+    fn brute_steps(corrupted: &[(usize, usize)]) -> Option<usize> {
+        let corrupted: HashSet<_> = corrupted.iter().copied().collect();
+        let mut seen = HashSet::from([(0, 0)]);
+        let mut queue = VecDeque::from([((0usize, 0usize), 0)]);
+        while let Some(((x, y), steps)) = queue.pop_front() {
+            if (x, y) == (6, 6) {
+                return Some(steps);
+            }
+            let next = [(x + 1, y), (x, y + 1), (x.wrapping_sub(1), y), (x, y.wrapping_sub(1))];
+            for cell in next {
+                if cell.0 < 7 && cell.1 < 7 && !corrupted.contains(&cell) && seen.insert(cell) {
+                    queue.push_back((cell, steps + 1));
+                }
+            }
+        }
+        None
+    }
+    proptest! {
+        #[test]
+        fn prop_matches_brute_force(bytes in arb_falling_bytes()) {
+            let input: String = bytes.iter().map(|(x, y)| format!("{x},{y}\n")).collect();
+            let ram_run = Day18::parse(&input).unwrap();
+            let steps = brute_steps(&bytes[..bytes.len().min(12)]);
+            prop_assert_eq!(Day18::part1(&ram_run), steps.map_or(Answer::Unsolved, Answer::from));
+            let blocking = (1..=bytes.len())
+                .find(|&n| brute_steps(&bytes[..n]).is_none())
+                .map(|n| Position { x: bytes[n - 1].0, y: bytes[n - 1].1 });
+            prop_assert_eq!(first_blocking_byte(&ram_run), blocking);
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use aoc_common::parse::Lines;
+use aoc_common::{Answer, ParseError, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct Day19;
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = Onsen;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(onsen: &Self::Parsed) -> Answer {
+        onsen.possible_patterns().len().into()
+    }
+    fn part2(onsen: &Self::Parsed) -> Answer {
+        onsen.all_combinations().into()
+    }
+}
+
+#[derive(Debug)]
+pub struct Onsen {
+    towels: Vec<String>,
+    displays: Vec<String>,
+}
+impl Onsen {
+    fn all_combinations(&self) -> usize {
+        fn recur<'input>(
+            cache: &mut HashMap<&'input str, usize>,
+            towels: &[String],
+            pattern: &'input str,
+        ) -> usize {
+            match pattern {
+                "" => 1,
+                _ => match cache.get(pattern) {
+                    Some(&count) => count,
+                    None => {
+                        let count = towels
+                            .iter()
+                            .filter_map(|t| pattern.strip_prefix(t))
+                            .fold(0, |acc, rest| acc + recur(cache, towels, rest));
+                        cache.insert(pattern, count);
+                        count
+                    }
+                },
+            }
+        }
+        let mut cache = HashMap::new();
+        self.displays
+            .iter()
+            .map(|pattern| recur(&mut cache, &self.towels, pattern))
+            .sum()
+    }
+    fn possible_patterns(&self) -> Vec<String> {
+        let regex = format!("^({})+$", self.towels.join("|"));
+        let regex = Regex::new(&regex).unwrap();
+        self.displays
+            .iter()
+            .filter(|s| regex.is_match(s))
+            .cloned()
+            .collect()
+    }
+}
+impl FromStr for Onsen {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Lines::new(input);
+        let line = lines.expect("towel patterns like \"r, wr, b\"")?;
+        let towels = line
+            .text
+            .split(", ")
+            .map(|towel| match towel {
+                "" => Err(line.error(towel, "a towel pattern")),
+                _ => match towel.char_indices().find(|(_, c)| !c.is_alphanumeric()) {
+                    Some((idx, _)) => Err(line.error(&towel[idx..], "a towel stripe color")),
+                    None => Ok(towel.to_string()),
+                },
+            })
+            .collect::<Result<_, _>>()?;
+        let separator = lines.expect("an empty line after the towels")?;
+        if !separator.text.is_empty() {
+            return Err(separator.error(separator.text, "an empty line after the towels"));
+        }
+        Ok(Self {
+            towels,
+            displays: lines
+                .filter(|line| !line.text.is_empty())
+                .map(|line| line.text.to_string())
+                .collect(),
+        })
+    }
+}
+
+/// Counts arrangements by trying every towel at every position, without a cache.
+#[cfg(any(test, feature = "reference"))]
+pub mod reference {
+    use crate::{Day19, Onsen};
+    use aoc_common::Answer;
+    use aoc_common::reference::Reference;
+    use proptest::prelude::*;
+
+    fn arrangements(towels: &[String], pattern: &str) -> usize {
+        match pattern {
+            "" => 1,
+            _ => towels
+                .iter()
+                .filter_map(|towel| pattern.strip_prefix(towel.as_str()))
+                .map(|rest| arrangements(towels, rest))
+                .sum(),
+        }
+    }
+    impl Reference for Day19 {
+        fn inputs() -> BoxedStrategy<String> {
+            (
+                prop::collection::btree_set("[wubrg]{1,3}", 1..6),
+                prop::collection::vec("[wubrg]{1,8}", 1..6),
+            )
+                .prop_map(|(towels, displays)| {
+                    let towels: Vec<String> = towels.into_iter().collect();
+                    format!("{}\n\n{}\n", towels.join(", "), displays.join("\n"))
+                })
+                .boxed()
+        }
+        fn reference_part1(onsen: &Onsen) -> Option<Answer> {
+            let possible = onsen
+                .displays
+                .iter()
+                .filter(|display| arrangements(&onsen.towels, display) > 0)
+                .count();
+            Some(possible.into())
+        }
+        fn reference_part2(onsen: &Onsen) -> Option<Answer> {
+            let all: usize = onsen
+                .displays
+                .iter()
+                .map(|display| arrangements(&onsen.towels, display))
+                .sum();
+            Some(all.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day19, Onsen};
+    use aoc_common::parse_input;
+    use aoc_common::reference::verify;
+
+    #[test]
+    fn test_part1() {
+        let onsen: Onsen = parse_input!("example-input.txt").unwrap();
+        assert_eq!(onsen.possible_patterns().len(), 6)
+    }
+    #[test]
+    fn test_part2() {
+        let onsen: Onsen = parse_input!("example-input.txt").unwrap();
+        assert_eq!(onsen.all_combinations(), 16)
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = "r, w|r, b\n\nbrwrr".parse::<Onsen>().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        let error = "r, wr, b\nbrwrr".parse::<Onsen>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: expected an empty line after the towels, found \"brwrr\""
+        );
+    }
+
+    #[test]
+    fn test_reference() {
+        assert_eq!(verify::<Day19>(64), Ok(()));
+    }
+}
@@ -0,0 +1,41 @@
+//! Times the Problem Dampener of day 2 on reports with thousands of levels, removing at most k
+//! levels: `cargo bench -p day2 --bench dampener`.
+use day2::{Safety, SafetyPolicy};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: usize = 100;
+
+/// Sorted durations of `ITERATIONS` runs of `f`.
+fn measure<T>(mut f: impl FnMut() -> T) -> Vec<Duration> {
+    let mut runs: Vec<Duration> = (0..ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    runs.sort();
+    runs
+}
+
+fn main() {
+    for levels in [1_000, 8_000, 30_000] {
+        let mut report: Vec<i16> = (0..levels).map(|level| level as i16 - 16_000).collect();
+        report[levels / 2] += 10;
+        for dampener in [1, 2] {
+            let policy = SafetyPolicy {
+                dampener,
+                ..SafetyPolicy::PART2
+            };
+            assert_eq!(policy.assess(&report), Safety::Dampened(vec![levels / 2]));
+            let runs = measure(|| policy.assess(&report));
+            println!(
+                "{levels:>6} levels, k={dampener}  min {:>10.2?}  median {:>10.2?}  p95 {:>10.2?}",
+                runs[0],
+                runs[ITERATIONS / 2],
+                runs[ITERATIONS * 95 / 100]
+            );
+        }
+    }
+}
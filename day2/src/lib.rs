@@ -0,0 +1,478 @@
+use aoc_common::parse::parse_lines;
+use aoc_common::{Answer, ParseError, Solution};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tracing::debug;
+
+pub struct Day2;
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = Vec<Vec<i16>>;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+    fn part1(reports: &Self::Parsed) -> Answer {
+        safe_reports(reports, &SafetyPolicy::PART1).into()
+    }
+    fn part2(reports: &Self::Parsed) -> Answer {
+        let policy = SafetyPolicy::PART2;
+        reports
+            .iter()
+            .filter(|report| {
+                let safety = policy.assess(report);
+                debug!(?report, %safety);
+                safety.is_safe()
+            })
+            .count()
+            .into()
+    }
+    fn details(reports: &Self::Parsed, part: u8) -> Option<String> {
+        let policy = match part {
+            1 => SafetyPolicy::PART1,
+            _ => SafetyPolicy::PART2,
+        };
+        Some(DetailedReport { reports, policy }.to_string())
+    }
+}
+
+pub fn parse<Type: FromStr>(input: &str) -> Result<Vec<Vec<Type>>, ParseError> {
+    parse_lines(input, |line| {
+        line.text
+            .split_whitespace()
+            .map(|val| line.parse(val, "a level"))
+            .collect()
+    })
+}
+
+/// Rules a report must follow to be safe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyPolicy {
+    /// Smallest change between adjacent levels, in either direction.
+    pub min_step: i16,
+    /// Largest change between adjacent levels, in either direction.
+    pub max_step: i16,
+    /// Whether the levels must all increase or all decrease.
+    pub monotonic: bool,
+    /// Number of levels the Problem Dampener may remove.
+    pub dampener: usize,
+}
+
+/// Rule of a [`SafetyPolicy`] broken by a level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    MinStep,
+    MaxStep,
+    Direction,
+}
+
+/// The first level breaking a rule, with the change from the level before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    /// Index of the level in the report.
+    pub level: usize,
+    pub step: i16,
+    pub rule: Rule,
+    pub policy: SafetyPolicy,
+}
+
+/// Verdict of [`SafetyPolicy::assess`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Safety {
+    Safe,
+    /// Safe once the dampener removed the levels at these indices.
+    Dampened(Vec<usize>),
+    /// Unsafe even with the dampener, explained by the first violation of the whole report.
+    Unsafe(Violation),
+}
+
+impl Safety {
+    pub fn is_safe(&self) -> bool {
+        !matches!(self, Safety::Unsafe(_))
+    }
+}
+
+/// Direction of the report so far, undecided until the first non-zero change.
+type Direction = i16;
+
+impl SafetyPolicy {
+    pub const PART1: Self = Self {
+        min_step: 1,
+        max_step: 3,
+        monotonic: true,
+        dampener: 0,
+    };
+    pub const PART2: Self = Self {
+        dampener: 1,
+        ..Self::PART1
+    };
+
+    /// Rule broken by changing by `step` after going in `direction`.
+    fn broken_rule(&self, direction: Direction, step: i16) -> Option<Rule> {
+        if step.abs() < self.min_step {
+            Some(Rule::MinStep)
+        } else if step.abs() > self.max_step {
+            Some(Rule::MaxStep)
+        } else if self.monotonic && direction * step.signum() < 0 {
+            Some(Rule::Direction)
+        } else {
+            None
+        }
+    }
+    fn turn(&self, direction: Direction, step: i16) -> Direction {
+        match self.monotonic && direction == 0 {
+            true => step.signum(),
+            false => direction,
+        }
+    }
+    /// First violation of the report as it is, without the dampener.
+    pub fn check(&self, report: &[i16]) -> Result<(), Violation> {
+        let mut direction = 0;
+        for (idx, w) in report.windows(2).enumerate() {
+            let step = w[1] - w[0];
+            if let Some(rule) = self.broken_rule(direction, step) {
+                return Err(Violation {
+                    level: idx + 1,
+                    step,
+                    rule,
+                    policy: *self,
+                });
+            }
+            direction = self.turn(direction, step);
+        }
+        Ok(())
+    }
+    pub fn assess(&self, report: &[i16]) -> Safety {
+        match self.check(report) {
+            Ok(()) => Safety::Safe,
+            Err(violation) if self.dampener == 0 => Safety::Unsafe(violation),
+            Err(violation) if self.dampener == 1 => match self.dampen_one(report) {
+                Some(removed) => Safety::Dampened(vec![removed]),
+                None => Safety::Unsafe(violation),
+            },
+            Err(violation) => match self.dampen(report) {
+                Some(removed) => Safety::Dampened(removed),
+                None => Safety::Unsafe(violation),
+            },
+        }
+    }
+    pub fn is_safe(&self, report: &[i16]) -> bool {
+        self.assess(report).is_safe()
+    }
+    /// Earliest level whose removal makes the unsafe report safe, in linear time: removing level
+    /// `r` works when the levels before and after it are valid and its neighbours fit together.
+    fn dampen_one(&self, report: &[i16]) -> Option<usize> {
+        let directions: &[Direction] = match self.monotonic {
+            true => &[1, -1],
+            false => &[0],
+        };
+        let fits = |direction, from: usize, to: usize| {
+            self.broken_rule(direction, report[to] - report[from])
+                .is_none()
+        };
+        directions
+            .iter()
+            .filter_map(|&direction| {
+                // Levels ending a step that breaks the rules, when going in `direction`
+                let mut broken = (1..report.len()).filter(|&idx| !fits(direction, idx - 1, idx));
+                let first = broken.next()?;
+                let last = broken.next_back().unwrap_or(first);
+                (last - 1..=first).find(|&removed| {
+                    removed == 0
+                        || removed == report.len() - 1
+                        || fits(direction, removed - 1, removed + 1)
+                })
+            })
+            .min()
+    }
+    /// Fewest levels to remove for the report to be safe, if the dampener may remove that many.
+    fn dampen(&self, report: &[i16]) -> Option<Vec<usize>> {
+        // best[i][direction + 1]: fewest removals before keeping level i, going in direction
+        #[derive(Clone, Copy)]
+        struct Kept {
+            removed: usize,
+            previous: Option<(usize, usize)>,
+        }
+        let mut best: Vec<[Option<Kept>; 3]> = vec![[None; 3]; report.len()];
+        for last in 0..report.len() {
+            if last <= self.dampener {
+                best[last][1] = Some(Kept {
+                    removed: last,
+                    previous: None,
+                });
+            }
+            for before in last.saturating_sub(self.dampener + 1)..last {
+                for (slot, kept) in best[before].into_iter().enumerate() {
+                    let Some(kept) = kept else { continue };
+                    let (direction, step) = (slot as i16 - 1, report[last] - report[before]);
+                    let removed = kept.removed + last - before - 1;
+                    if removed > self.dampener || self.broken_rule(direction, step).is_some() {
+                        continue;
+                    }
+                    let next = &mut best[last][(self.turn(direction, step) + 1) as usize];
+                    // Ties keep the later level, removing the earlier ones like the puzzle does
+                    if next.is_none_or(|next| removed <= next.removed) {
+                        *next = Some(Kept {
+                            removed,
+                            previous: Some((before, slot)),
+                        });
+                    }
+                }
+            }
+        }
+        let (mut last, mut slot, _) = (0..report.len())
+            .flat_map(|last| (0..3).map(move |slot| (last, slot)))
+            .filter_map(|(last, slot)| {
+                let removed = best[last][slot]?.removed + report.len() - 1 - last;
+                (removed <= self.dampener).then_some((last, slot, removed))
+            })
+            .min_by_key(|&(_, _, removed)| removed)?;
+        let mut kept = vec![false; report.len()];
+        loop {
+            kept[last] = true;
+            match best[last][slot].unwrap().previous {
+                Some(previous) => (last, slot) = previous,
+                None => break,
+            }
+        }
+        Some((0..report.len()).filter(|&idx| !kept[idx]).collect())
+    }
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (level, step) = (self.level + 1, self.step);
+        match self.rule {
+            Rule::MinStep => write!(
+                f,
+                "level {level} changes by {step}, less than {}",
+                self.policy.min_step
+            ),
+            Rule::MaxStep => write!(
+                f,
+                "level {level} changes by {step}, more than {}",
+                self.policy.max_step
+            ),
+            Rule::Direction => write!(
+                f,
+                "level {level} changes by {step}, against the direction of the levels before"
+            ),
+        }
+    }
+}
+
+impl Display for Safety {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Safety::Safe => write!(f, "safe"),
+            Safety::Dampened(removed) => {
+                let levels: Vec<String> = removed.iter().map(|idx| (idx + 1).to_string()).collect();
+                write!(f, "safe without level(s) {}", levels.join(", "))
+            }
+            Safety::Unsafe(violation) => write!(f, "unsafe, {violation}"),
+        }
+    }
+}
+
+/// Verdict of every report on a line, telling which levels the dampener removed.
+pub struct DetailedReport<'a> {
+    pub reports: &'a [Vec<i16>],
+    pub policy: SafetyPolicy,
+}
+
+impl Display for DetailedReport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for report in self.reports {
+            let levels: Vec<String> = report.iter().map(i16::to_string).collect();
+            writeln!(f, "{}: {}", levels.join(" "), self.policy.assess(report))?;
+        }
+        Ok(())
+    }
+}
+
+pub fn safe_reports(input: &[Vec<i16>], policy: &SafetyPolicy) -> usize {
+    input.iter().filter(|row| policy.is_safe(row)).count()
+}
+#[cfg(test)]
+mod tests {
+    use crate::{Day2, DetailedReport, Rule, Safety, SafetyPolicy, Violation, parse, safe_reports};
+    use aoc_common::input::read_to_string;
+    use aoc_common::{Answer, Solution, read_input};
+    use proptest::prelude::*;
+
+    fn example() -> Vec<Vec<i16>> {
+        parse(&read_to_string(read_input!("example-input.txt").unwrap()).unwrap()).unwrap()
+    }
+    #[test]
+    fn test_safe_reports() {
+        let data = example();
+        assert_eq!(2, safe_reports(&data, &SafetyPolicy::PART1));
+    }
+    #[test]
+    fn test_dampened() {
+        let data = example();
+        let with_dempener = data
+            .iter()
+            .filter(|row| SafetyPolicy::PART2.is_safe(row.as_slice()))
+            .count();
+        assert_eq!(4, with_dempener);
+    }
+    #[test]
+    fn test_explanations() {
+        let policy = SafetyPolicy::PART2;
+        let verdicts: Vec<String> = example()
+            .iter()
+            .map(|report| policy.assess(report).to_string())
+            .collect();
+        assert_eq!(
+            verdicts,
+            [
+                "safe",
+                "unsafe, level 3 changes by 5, more than 3",
+                "unsafe, level 4 changes by -4, more than 3",
+                "safe without level(s) 2",
+                "safe without level(s) 3",
+                "safe",
+            ]
+        );
+        assert_eq!(
+            SafetyPolicy::PART1.check(&[1, 3, 2, 4, 5]),
+            Err(Violation {
+                level: 2,
+                step: -1,
+                rule: Rule::Direction,
+                policy: SafetyPolicy::PART1,
+            })
+        );
+    }
+    #[test]
+    fn test_detailed_report() {
+        let reports = example();
+        let details = DetailedReport {
+            reports: &reports[2..4],
+            policy: SafetyPolicy::PART2,
+        };
+        assert_eq!(
+            details.to_string(),
+            "9 7 6 2 1: unsafe, level 4 changes by -4, more than 3\n\
+             1 3 2 4 5: safe without level(s) 2\n"
+        );
+    }
+    #[test]
+    fn test_dampen_long_report() {
+        let mut report: Vec<i16> = (0..5000).collect();
+        report[2500] = 0;
+        assert_eq!(
+            SafetyPolicy::PART2.assess(&report),
+            Safety::Dampened(vec![2500])
+        );
+        report[4000] = 0;
+        assert!(!SafetyPolicy::PART2.is_safe(&report));
+    }
+    #[test]
+    fn test_policies() {
+        let report = [1, 2, 9, 3, 10, 4];
+        assert!(!SafetyPolicy::PART2.is_safe(&report));
+        let two = SafetyPolicy {
+            dampener: 2,
+            ..SafetyPolicy::PART1
+        };
+        assert_eq!(two.assess(&report), Safety::Dampened(vec![2, 4]));
+        let wide = SafetyPolicy {
+            max_step: 7,
+            monotonic: false,
+            ..SafetyPolicy::PART1
+        };
+        assert_eq!(wide.assess(&report), Safety::Safe);
+        let flat = SafetyPolicy {
+            min_step: 0,
+            ..SafetyPolicy::PART1
+        };
+        assert!(flat.is_safe(&[3, 3, 2, 2, 1]));
+        assert!(!flat.is_safe(&[3, 3, 4, 2]));
+    }
+
+    /// Random walks with steps of -4..=4, so that safe and almost safe reports are common.
+    fn arb_reports() -> impl Strategy<Value = Vec<Vec<i16>>> {
+        let report =
+            (0..20i16, prop::collection::vec(-4..=4i16, 0..8)).prop_map(|(start, steps)| {
+                steps.iter().fold(vec![start], |mut levels, step| {
+                    levels.push(levels.last().unwrap() + step);
+                    levels
+                })
+            });
+        prop::collection::vec(report, 0..10)
+    }
+    fn brute_safe(report: &[i16]) -> bool {
+        let diffs: Vec<i16> = report.windows(2).map(|w| w[1] - w[0]).collect();
+        diffs.iter().all(|d| (1..=3).contains(d)) || diffs.iter().all(|d| (-3..=-1).contains(d))
+    }
+    fn brute_dampened(report: &[i16]) -> bool {
+        brute_safe(report)
+            || (0..report.len()).any(|skip| {
+                let mut dampened = report.to_vec();
+                dampened.remove(skip);
+                brute_safe(&dampened)
+            })
+    }
+    fn brute_policy_safe(policy: &SafetyPolicy, report: &[i16]) -> bool {
+        let steps: Vec<i16> = report.windows(2).map(|w| w[1] - w[0]).collect();
+        let sized = steps
+            .iter()
+            .all(|s| (policy.min_step..=policy.max_step).contains(&s.abs()));
+        let directions =
+            (!policy.monotonic) || steps.iter().all(|s| *s >= 0) || steps.iter().all(|s| *s <= 0);
+        sized && directions
+    }
+    fn brute_policy_dampened(policy: &SafetyPolicy, report: &[i16], removals: usize) -> bool {
+        brute_policy_safe(policy, report)
+            || (removals > 0
+                && (0..report.len()).any(|skip| {
+                    let mut dampened = report.to_vec();
+                    dampened.remove(skip);
+                    brute_policy_dampened(policy, &dampened, removals - 1)
+                }))
+    }
+    fn arb_policy() -> impl Strategy<Value = SafetyPolicy> {
+        (0..3i16, 0..4i16, any::<bool>(), 0..4usize).prop_map(
+            |(min_step, extra, monotonic, dampener)| SafetyPolicy {
+                min_step,
+                max_step: min_step + extra,
+                monotonic,
+                dampener,
+            },
+        )
+    }
+    proptest! {
+        #[test]
+        fn prop_policy_matches_brute_force(
+            policy in arb_policy(),
+            report in prop::collection::vec(0..12i16, 0..8),
+        ) {
+            let safety = policy.assess(&report);
+            prop_assert_eq!(
+                safety.is_safe(),
+                brute_policy_dampened(&policy, &report, policy.dampener)
+            );
+            if let Safety::Dampened(removed) = safety {
+                prop_assert!(removed.len() <= policy.dampener);
+                let kept: Vec<i16> = (0..report.len())
+                    .filter(|idx| !removed.contains(idx))
+                    .map(|idx| report[idx])
+                    .collect();
+                prop_assert_eq!(policy.check(&kept), Ok(()));
+            }
+        }
+        #[test]
+        fn prop_matches_brute_force(reports in arb_reports()) {
+            let input: String = reports
+                .iter()
+                .map(|report| report.iter().map(|l| l.to_string()).collect::<Vec<_>>().join(" ") + "\n")
+                .collect();
+            let parsed = Day2::parse(&input).unwrap();
+            let count = |safe: fn(&[i16]) -> bool| reports.iter().filter(|r| safe(r)).count();
+            prop_assert_eq!(Day2::part1(&parsed), Answer::from(count(brute_safe)));
+            prop_assert_eq!(Day2::part2(&parsed), Answer::from(count(brute_dampened)));
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use aoc_common::geometry::{Direction, IPoint};
+use aoc_common::parse::Lines;
+use aoc_common::search::bfs;
+use aoc_common::visualize::{Animate, Color, Frame, Visualize};
+use aoc_common::{Answer, Grid, ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub struct Day20;
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = RaceCondition;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(race: &Self::Parsed) -> Answer {
+        race.saves_at_least(2, 100).map_or(Answer::Unsolved, Answer::from)
+    }
+    fn part2(race: &Self::Parsed) -> Answer {
+        race.saves_at_least(20, 100).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+type Position = IPoint;
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+enum Tile {
+    Wall,
+    Start,
+    End,
+    Path,
+}
+pub struct RaceCondition {
+    map: Grid<Tile>,
+    start: Position,
+    end: Position,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Wall => write!(f, "#"),
+            Tile::Start => write!(f, "S"),
+            Tile::End => write!(f, "E"),
+            Tile::Path => write!(f, "."),
+        }
+    }
+}
+impl RaceCondition {
+    fn at(&self, pos: Position) -> Option<&Tile> {
+        pos.unsigned().and_then(|cell| self.map.get(cell))
+    }
+    fn next_nodes<T: FnMut(&Tile) -> bool + 'static>(
+        &self,
+        from: Position,
+        mut cond: T,
+    ) -> impl Iterator<Item = Position> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| from + direction)
+            .filter(move |pos| match self.at(*pos) {
+                Some(t) => cond(t),
+                None => false,
+            })
+    }
+
+    fn best_path(&self) -> Option<Vec<Position>> {
+        bfs(
+            self.start,
+            |&current| self.next_nodes(current, |tile| tile == &Tile::End || tile == &Tile::Path),
+            |&current| current == self.end,
+        )
+        .map(|path| path.nodes)
+    }
+
+    /// Cheats saving `min_saves` picoseconds or more, `None` when the end can't be reached.
+    pub fn saves_at_least(&self, jump_lenght: isize, min_saves: usize) -> Option<usize> {
+        let saves = self.cheat_saves(jump_lenght)?;
+        Some(
+            saves
+                .iter()
+                .filter_map(|(saves, count)| if *saves >= min_saves { Some(count) } else { None })
+                .sum(),
+        )
+    }
+    fn cheat_saves(&self, jump_lenght: isize) -> Option<HashMap<usize, usize>> {
+        Some(count_cheats(&self.best_path()?, jump_lenght))
+    }
+}
+fn count_from(
+    best_path: &[Position],
+    jump_lenght: isize,
+) -> impl Fn(HashMap<usize, usize>, usize) -> HashMap<usize, usize> + Sync + '_ {
+    move |mut saves_count, start_idx| {
+        for saves in cheats_from(best_path, start_idx, jump_lenght) {
+            *saves_count.entry(saves).or_insert(0) += 1;
+        }
+        saves_count
+    }
+}
+#[cfg(not(feature = "parallel"))]
+fn count_cheats(best_path: &[Position], jump_lenght: isize) -> HashMap<usize, usize> {
+    (0..best_path.len()).fold(HashMap::new(), count_from(best_path, jump_lenght))
+}
+/// Every thread counts the cheats of some starts, the counts are then added up.
+#[cfg(feature = "parallel")]
+fn count_cheats(best_path: &[Position], jump_lenght: isize) -> HashMap<usize, usize> {
+    (0..best_path.len())
+        .into_par_iter()
+        .fold(HashMap::new, count_from(best_path, jump_lenght))
+        .reduce(HashMap::new, |mut total, saves_count| {
+            for (saves, count) in saves_count {
+                *total.entry(saves).or_insert(0) += count;
+            }
+            total
+        })
+}
+/// Time saved by every cheat starting at `best_path[start_idx]`.
+fn cheats_from(
+    best_path: &[Position],
+    start_idx: usize,
+    jump_lenght: isize,
+) -> impl Iterator<Item = usize> + '_ {
+    let start = best_path[start_idx];
+    best_path[start_idx..]
+        .iter()
+        .enumerate()
+        .map(move |(end_idx, end)| (end_idx, start.manhattan(*end) as isize))
+        .filter(move |(_, dist)| dist <= &jump_lenght)
+        .filter_map(|(end_idx, dist)| {
+            let saves = end_idx as isize - dist;
+            if saves > 0 { Some(saves as usize) } else { None }
+        })
+}
+impl Tile {
+    fn from_char(c: char) -> Option<Tile> {
+        match c {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Path),
+            'S' => Some(Tile::Start),
+            'E' => Some(Tile::End),
+            _ => None,
+        }
+    }
+}
+impl FromStr for RaceCondition {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Lines::new(input);
+        let map = Grid::parse(&mut lines, Tile::from_char, "a racetrack tile")?;
+        let find = |tile: Tile, expected: &str| {
+            map.position(|t| t == &tile)
+                .map(|cell| cell.signed())
+                .ok_or_else(|| ParseError::end_of_input(lines.number() + 1, 1, expected))
+        };
+        Ok(Self {
+            start: find(Tile::Start, "a start tile 'S'")?,
+            end: find(Tile::End, "an end tile 'E'")?,
+            map,
+        })
+    }
+}
+impl Animate for Day20 {
+    fn animate(race: RaceCondition) -> Box<dyn Visualize> {
+        let best_path = race.best_path().unwrap_or_default();
+        Box::new(Race { race, best_path, picoseconds: 0 })
+    }
+}
+/// The program racing along the track one picosecond per step, with the best cheat from where
+/// it is.
+struct Race {
+    race: RaceCondition,
+    best_path: Vec<Position>,
+    picoseconds: usize,
+}
+impl Race {
+    /// Index on the best path where the best cheat of `jump_lenght` from the current tile lands.
+    fn best_cheat(&self, jump_lenght: isize) -> Option<(usize, usize)> {
+        let start = *self.best_path.get(self.picoseconds)?;
+        (self.picoseconds..self.best_path.len())
+            .filter(|&end_idx| start.manhattan(self.best_path[end_idx]) as isize <= jump_lenght)
+            .map(|end_idx| {
+                let dist = start.manhattan(self.best_path[end_idx]);
+                (end_idx, (end_idx - self.picoseconds).saturating_sub(dist))
+            })
+            .filter(|&(_, saves)| saves > 0)
+            .max_by_key(|&(_, saves)| saves)
+    }
+}
+impl Visualize for Race {
+    fn frame(&self) -> Frame {
+        let mut frame = Frame::from_display(&self.race);
+        frame.paint("#", Color::Gray);
+        frame.paint("SE", Color::Cyan);
+        let cells =
+            |path: &[Position]| path.iter().filter_map(|pos| pos.unsigned()).collect::<Vec<_>>();
+        let walked = &self.best_path[..self.picoseconds.min(self.best_path.len())];
+        frame.highlight(cells(walked), Some('O'), Color::Green);
+        let last = self.best_path.len().saturating_sub(1);
+        let mut caption = format!("Picosecond {}/{last}", self.picoseconds);
+        if let Some((end_idx, saves)) = self.best_cheat(2) {
+            let landing = cells(&self.best_path[end_idx..=end_idx]);
+            frame.highlight(landing, Some('*'), Color::Magenta);
+            caption += &format!(", the best cheat from here saves {saves} picoseconds");
+        }
+        if let Some(&current) = self.best_path.get(self.picoseconds) {
+            frame.highlight(cells(&[current]), Some('@'), Color::Red);
+        }
+        frame.with_caption(caption)
+    }
+    fn step(&mut self) -> bool {
+        if self.picoseconds + 1 >= self.best_path.len() {
+            return false;
+        }
+        self.picoseconds += 1;
+        true
+    }
+}
+impl Display for RaceCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use crate::{Day20, RaceCondition};
+    use aoc_common::{Answer, Solution, parse_input};
+    use aoc_common::visualize::Animate;
+
+    #[test]
+    fn test_part1() {
+        let race_condition: RaceCondition = parse_input!("example-input.txt").unwrap();
+        let save_count = race_condition.cheat_saves(2).unwrap();
+        assert_eq!(1, save_count[&64]);
+        assert_eq!(1, save_count[&40]);
+        assert_eq!(1, save_count[&38]);
+        assert_eq!(1, save_count[&20]);
+        assert_eq!(3, save_count[&12]);
+        assert_eq!(2, save_count[&10]);
+        assert_eq!(4, save_count[&8]);
+        assert_eq!(2, save_count[&6]);
+        assert_eq!(14, save_count[&4]);
+        assert_eq!(14, save_count[&2]);
+    }
+    #[test]
+    fn test_part2() {
+        let race_condition: RaceCondition = parse_input!("example-input.txt").unwrap();
+        let save_count = race_condition.cheat_saves(20).unwrap();
+        assert_eq!(3, save_count[&76]);
+        assert_eq!(4, save_count[&74]);
+        assert_eq!(22, save_count[&72]);
+        assert_eq!(12, save_count[&70]);
+        assert_eq!(14, save_count[&68]);
+
+        assert_eq!(12, save_count[&66]);
+        assert_eq!(19, save_count[&64]);
+        assert_eq!(20, save_count[&62]);
+
+        assert_eq!(12, save_count[&66]);
+        assert_eq!(19, save_count[&64]);
+        assert_eq!(20, save_count[&62]);
+
+        assert_eq!(23, save_count[&60]);
+        assert_eq!(25, save_count[&58]);
+        assert_eq!(39, save_count[&56]);
+
+        assert_eq!(29, save_count[&54]);
+        assert_eq!(31, save_count[&52]);
+        assert_eq!(32, save_count[&50]);
+    }
+    #[test]
+    fn test_unreachable_end() {
+        let race_condition: RaceCondition = "#####\n#S#E#\n#####".parse().unwrap();
+        assert_eq!(Day20::part1(&race_condition), Answer::Unsolved);
+        assert_eq!(Day20::part2(&race_condition), Answer::Unsolved);
+    }
+    #[test]
+    fn test_visualize() {
+        let race_condition: RaceCondition = parse_input!("example-input.txt").unwrap();
+        let mut race = Day20::animate(race_condition);
+        assert_eq!(
+            race.frame().caption,
+            "Picosecond 0/84, the best cheat from here saves 4 picoseconds"
+        );
+        while race.step() {}
+        assert_eq!(race.frame().caption, "Picosecond 84/84");
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = "#####\n#S.E#\n#..##\n####".parse::<RaceCondition>().err().unwrap();
+        assert_eq!((error.line, error.column), (4, 5));
+        let error = "#####\n#..E#\n#####".parse::<RaceCondition>().err().unwrap();
+        assert_eq!(error.expected, "a start tile 'S'");
+    }
+}
@@ -0,0 +1,226 @@
+use aoc_common::{Answer, ParseError, Solution};
+
+pub struct Day3;
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = Vec<Token>;
+    /// Corrupted memory, any input is valid.
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(Lexer::new(input).collect())
+    }
+    fn part1(tokens: &Self::Parsed) -> Answer {
+        sum_of_results(execute(tokens.iter().copied(), false)).into()
+    }
+    fn part2(tokens: &Self::Parsed) -> Answer {
+        sum_of_results(execute(tokens.iter().copied(), true)).into()
+    }
+}
+
+/// Instructions hidden in the corrupted memory. A new kind needs a variant, its name in
+/// [`Instruction::NAMES`], its arguments in `Instruction::new`, its [`Instruction::result`] and
+/// its effect in [`Machine::step`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i64, i64),
+    Do,
+    Dont,
+}
+
+impl Instruction {
+    pub const NAMES: [&str; 3] = ["mul", "do", "don't"];
+
+    /// Instruction called `name` with `args`, `None` when they don't fit.
+    fn new(name: &str, args: &[i64]) -> Option<Self> {
+        match (name, args) {
+            ("mul", &[a, b]) => Some(Self::Mul(a, b)),
+            ("do", []) => Some(Self::Do),
+            ("don't", []) => Some(Self::Dont),
+            _ => None,
+        }
+    }
+    /// Value added to the answer when the instruction is executed.
+    pub fn result(&self) -> i64 {
+        match self {
+            Self::Mul(a, b) => a * b,
+            Self::Do | Self::Dont => 0,
+        }
+    }
+}
+
+/// Instruction found at a byte offset of the memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub offset: usize,
+    pub instruction: Instruction,
+}
+
+/// Finds the well-formed instructions, `name(1,2)`, in corrupted memory. Arguments have 1 to 3
+/// digits, as in the puzzle, so that their products can't overflow.
+pub struct Lexer<'a> {
+    memory: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(memory: &'a str) -> Self {
+        Self {
+            memory: memory.as_bytes(),
+            offset: 0,
+        }
+    }
+    /// Instruction starting at `start` and the offset after it.
+    fn instruction_at(&self, start: usize) -> Option<(Instruction, usize)> {
+        let rest = &self.memory[start..];
+        Instruction::NAMES.iter().find_map(|name| {
+            let mut rest = rest.strip_prefix(name.as_bytes())?.strip_prefix(b"(")?;
+            let mut args = Vec::new();
+            if let Some(after) = rest.strip_prefix(b")") {
+                rest = after;
+            } else {
+                loop {
+                    let digits = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+                    if !(1..=3).contains(&digits) {
+                        return None;
+                    }
+                    args.push(std::str::from_utf8(&rest[..digits]).ok()?.parse().ok()?);
+                    rest = &rest[digits..];
+                    match rest.split_first()? {
+                        (b',', after) => rest = after,
+                        (b')', after) => {
+                            rest = after;
+                            break;
+                        }
+                        _ => return None,
+                    }
+                }
+            }
+            let instruction = Instruction::new(name, &args)?;
+            Some((instruction, self.memory.len() - rest.len()))
+        })
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Token;
+    fn next(&mut self) -> Option<Token> {
+        while self.offset < self.memory.len() {
+            let start = self.offset;
+            self.offset += 1;
+            if let Some((instruction, end)) = self.instruction_at(start) {
+                self.offset = end;
+                return Some(Token {
+                    offset: start,
+                    instruction,
+                });
+            }
+        }
+        None
+    }
+}
+
+/// Enabled state of the program. Without conditionals `do()` and `don't()` are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Machine {
+    pub enabled: bool,
+    pub conditionals: bool,
+}
+
+impl Machine {
+    pub fn new(conditionals: bool) -> Self {
+        Self {
+            enabled: true,
+            conditionals,
+        }
+    }
+    /// Runs `instruction`, returning whether it was executed.
+    pub fn step(&mut self, instruction: Instruction) -> bool {
+        match instruction {
+            Instruction::Mul(..) => self.enabled,
+            Instruction::Do | Instruction::Dont if !self.conditionals => false,
+            Instruction::Do => {
+                self.enabled = true;
+                true
+            }
+            Instruction::Dont => {
+                self.enabled = false;
+                true
+            }
+        }
+    }
+}
+
+/// The executed tokens, in order.
+pub fn execute(
+    tokens: impl IntoIterator<Item = Token>,
+    conditionals: bool,
+) -> impl Iterator<Item = Token> {
+    let mut machine = Machine::new(conditionals);
+    tokens
+        .into_iter()
+        .filter(move |token| machine.step(token.instruction))
+}
+
+pub fn sum_of_results(executed: impl Iterator<Item = Token>) -> i64 {
+    executed.map(|token| token.instruction.result()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Instruction::*;
+    use crate::{Day3, Lexer, Token, execute, sum_of_results};
+
+    aoc_common::example_tests!(Day3, "examples.txt", [mul, do_dont]);
+
+    fn run(memory: &str, conditionals: bool) -> Vec<(usize, crate::Instruction)> {
+        execute(Lexer::new(memory), conditionals)
+            .map(|token| (token.offset, token.instruction))
+            .collect()
+    }
+
+    #[test]
+    fn test_lexer() {
+        let tokens: Vec<Token> =
+            Lexer::new("mul(1,2)mul(3,4]mul(,5)do()mul( 6,7)mul(8,9,)").collect();
+        assert_eq!(
+            tokens,
+            [
+                Token {
+                    offset: 0,
+                    instruction: Mul(1, 2)
+                },
+                Token {
+                    offset: 23,
+                    instruction: Do
+                },
+            ]
+        );
+        let tokens: Vec<_> = Lexer::new("don't()do(1)mul(2)ämul(123,4)").collect();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[1].offset, 20);
+        assert_eq!(Lexer::new("mul(99999999999999999999,1)").count(), 0);
+        assert_eq!(
+            Lexer::new("mul(99999999999,99999999999)mul(1234,5)").count(),
+            0
+        );
+        let tokens: Vec<_> = Lexer::new("mul(999,999)").map(|t| t.instruction).collect();
+        assert_eq!(tokens, [Mul(999, 999)]);
+    }
+    #[test]
+    fn test_interpreter() {
+        let memory = "mul(1,2)don't()mul(3,4)do()mul(5,6)";
+        assert_eq!(
+            run(memory, false),
+            [(0, Mul(1, 2)), (15, Mul(3, 4)), (27, Mul(5, 6))]
+        );
+        assert_eq!(
+            run(memory, true),
+            [(0, Mul(1, 2)), (8, Dont), (23, Do), (27, Mul(5, 6))]
+        );
+        // do() before any don't(), and a don't() never closed
+        let memory = "do()mul(2,3)don't()do()don't()mul(4,5)";
+        let executed = execute(Lexer::new(memory), true);
+        assert_eq!(sum_of_results(executed), 6);
+        assert_eq!(run("don't()mul(1,1)", true), [(0, Dont)]);
+    }
+}
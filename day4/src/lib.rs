@@ -0,0 +1,98 @@
+use aoc_common::geometry::Compass;
+use aoc_common::grid::Cell;
+use aoc_common::parse::Lines;
+use aoc_common::{Answer, Grid, ParseError, Solution};
+use std::str::FromStr;
+
+pub struct Day4;
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = Puzzle;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(puzzle: &Self::Parsed) -> Answer {
+        puzzle.xmas_count().into()
+    }
+    fn part2(puzzle: &Self::Parsed) -> Answer {
+        puzzle.cross_count().into()
+    }
+}
+#[derive(Debug, Default)]
+pub struct Puzzle {
+    letters: Grid<char>,
+}
+impl Puzzle {
+    fn xmas_with_start(&self, cell: Cell) -> usize {
+        let word = "XMAS";
+        Compass::ALL
+            .into_iter()
+            .filter(|&dir| {
+                let mut tmp_cell = Some(cell);
+                word.chars().all(|char| match tmp_cell {
+                    Some(current) if self.letters[current] == char => {
+                        tmp_cell = self.letters.offset(current, dir);
+                        true
+                    }
+                    _ => false,
+                })
+            })
+            .count()
+    }
+    pub fn xmas_count(&self) -> usize {
+        self.letters
+            .iter()
+            .filter(|&(_, &c)| c == 'X')
+            .map(|(cell, _)| self.xmas_with_start(cell))
+            .sum()
+    }
+    fn cross_with_middle(&self, cell: Cell) -> bool {
+        let diagonals = [
+            Compass::NorthWest,
+            Compass::NorthEast,
+            Compass::SouthEast,
+            Compass::SouthWest,
+        ];
+        let neighbours = diagonals
+            .into_iter()
+            .map_while(|dir| self.letters.offset(cell, dir))
+            .map(|cell| self.letters[cell])
+            .collect::<String>();
+        matches!(neighbours.as_str(), "SSMM" | "SMMS" | "MMSS" | "MSSM")
+    }
+    pub fn cross_count(&self) -> usize {
+        self.letters
+            .iter()
+            .filter(|&(cell, &c)| c == 'A' && self.cross_with_middle(cell))
+            .count()
+    }
+}
+
+impl FromStr for Puzzle {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let letter = |c| "XMAS".contains(c).then_some(c);
+        Ok(Puzzle {
+            letters: Grid::parse(&mut Lines::new(input), letter, "one of X, M, A, S")?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::Puzzle;
+    use aoc_common::parse_input;
+
+    #[test]
+    fn test_part1() {
+        let puzzle: Puzzle = parse_input!("example-input.txt").unwrap();
+        assert_eq!(18, puzzle.xmas_count());
+    }
+    #[test]
+    fn test_part2() {
+        let puzzle: Puzzle = parse_input!("example-input.txt").unwrap();
+        assert_eq!(9, puzzle.cross_count());
+    }
+}
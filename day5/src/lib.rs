@@ -0,0 +1,240 @@
+use aoc_common::parse::{Line, Lines};
+use aoc_common::{Answer, ParseError, Solution};
+use std::cmp::Ordering::{Equal, Greater, Less};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+pub struct Day5;
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = PrintQueue;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(queue: &Self::Parsed) -> Answer {
+        queue.middle_pages().into()
+    }
+    fn part2(queue: &Self::Parsed) -> Answer {
+        queue.incorrect_updates().into()
+    }
+}
+
+struct Rule {
+    first: usize,
+    latter: usize,
+}
+
+impl Rule {
+    fn parse(line: Line) -> Result<Self, ParseError> {
+        let (first, latter) = line.split_once("|", "a rule like 47|53")?;
+        Ok(Self {
+            first: line.parse(first, "a page number")?,
+            latter: line.parse(latter, "a page number")?,
+        })
+    }
+}
+pub struct PrintQueue {
+    rules: Vec<Rule>,
+    prints: Vec<Vec<usize>>,
+    must_print_before: HashMap<usize, HashSet<usize>>,
+    must_print_after: HashMap<usize, HashSet<usize>>,
+}
+impl PrintQueue {
+    fn new(rules: Vec<Rule>, prints: Vec<Vec<usize>>) -> PrintQueue {
+        let mut pq = PrintQueue {
+            rules,
+            prints,
+            must_print_before: HashMap::new(),
+            must_print_after: HashMap::new(),
+        };
+        for rule in &mut pq.rules {
+            pq.must_print_before
+                .entry(rule.first)
+                .or_default()
+                .insert(rule.latter);
+            pq.must_print_after
+                .entry(rule.latter)
+                .or_default()
+                .insert(rule.first);
+        }
+        pq
+    }
+    pub fn violates_rule(&self, pages: &[usize]) -> bool {
+        for (idx, page) in pages.iter().enumerate() {
+            let printed = &pages[..idx];
+            let to_print = &pages[idx + 1..];
+            if let Some(ensure_after) = self.must_print_before.get(page)
+                && printed.iter().any(|&page| ensure_after.contains(&page))
+            {
+                return false;
+            }
+            if let Some(ensure_before) = self.must_print_after.get(page)
+                && to_print.iter().any(|&page| ensure_before.contains(&page))
+            {
+                return false;
+            }
+        }
+        true
+    }
+    pub fn middle_pages(&self) -> usize {
+        self.prints
+            .iter()
+            .filter(|print| self.violates_rule(print))
+            .map(|print| print[print.len() / 2])
+            .sum()
+    }
+    fn order_two_pages(&self, left: &usize, right: &usize) -> core::cmp::Ordering {
+        if let Some(ensure_after) = self.must_print_before.get(left)
+            && ensure_after.contains(right)
+        {
+            return Less;
+        }
+        if let Some(ensure_before) = self.must_print_after.get(left)
+            && ensure_before.contains(right)
+        {
+            return Greater;
+        }
+        if let Some(ensure_after) = self.must_print_before.get(right)
+            && ensure_after.contains(left)
+        {
+            return Less;
+        }
+        if let Some(ensure_before) = self.must_print_after.get(right)
+            && ensure_before.contains(left)
+        {
+            return Greater;
+        }
+        Equal
+    }
+    fn order_rule(&self, mut pages: Vec<usize>) -> Vec<usize> {
+        pages.sort_unstable_by(|left, right| self.order_two_pages(left, right));
+        pages
+    }
+    pub fn incorrect_updates(&self) -> usize {
+        self.prints
+            .iter()
+            .filter(|print| !self.violates_rule(print))
+            .cloned()
+            .map(|print| self.order_rule(print))
+            .map(|print| print[print.len() / 2])
+            .sum()
+    }
+}
+impl FromStr for PrintQueue {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Lines::new(input);
+        let rules = lines.section().map(Rule::parse).collect::<Result<_, _>>()?;
+        let pages = lines
+            .filter(|line| !line.text.is_empty())
+            .map(|line| {
+                line.text
+                    .split(',')
+                    .map(|page| line.parse(page, "a page number"))
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(PrintQueue::new(rules, pages))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Day5, PrintQueue};
+    use aoc_common::{Answer, Solution, parse_input};
+    use proptest::prelude::*;
+    use std::cmp::Ordering::{Greater};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_part1() {
+        let print_queue: PrintQueue = parse_input!("example-input.txt").unwrap();
+        assert_eq!(print_queue.middle_pages(), 143);
+    }
+    #[test]
+    fn test_part2() {
+        let print_queue: PrintQueue = parse_input!("example-input.txt").unwrap();
+        assert_eq!(print_queue.order_two_pages(&75, &97), Greater);
+        assert_eq!(print_queue.order_two_pages(&13, &29), Greater);
+        assert_eq!(print_queue.order_two_pages(&13, &75), Greater);
+        assert_eq!(print_queue.order_rule(vec![75, 97, 47, 61, 53]), vec![
+            97, 75, 47, 61, 53
+        ]);
+        assert_eq!(print_queue.order_rule(vec![61, 13, 29]), vec![61, 29, 13]);
+        assert_eq!(print_queue.order_rule(vec![97, 13, 75, 29, 47]), vec![
+            97, 75, 47, 29, 13
+        ]);
+        assert_eq!(print_queue.incorrect_updates(), 123);
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = "47|53\n97-13\n\n75,47".parse::<PrintQueue>().err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: expected a rule like 47|53, found end of line"
+        );
+    }
+
+    /// Hidden page order with a rule for every pair of pages, as in the puzzle, and updates
+    /// printing some of the pages in any order.
+    fn arb_print_queue() -> impl Strategy<Value = (Vec<usize>, Vec<Vec<usize>>)> {
+        (2..8usize)
+            .prop_flat_map(|n| prop::sample::subsequence((10..100).collect::<Vec<usize>>(), n))
+            .prop_shuffle()
+            .prop_flat_map(|order| {
+                let update =
+                    prop::sample::subsequence(order.clone(), 1..=order.len()).prop_shuffle();
+                (Just(order), prop::collection::vec(update, 1..6))
+            })
+    }
+    fn brute_print_queue(
+        rules: &HashSet<(usize, usize)>,
+        updates: &[Vec<usize>],
+    ) -> (usize, usize) {
+        let in_order = |update: &Vec<usize>| {
+            (0..update.len())
+                .all(|i| (i..update.len()).all(|j| !rules.contains(&(update[j], update[i]))))
+        };
+        let reorder = |update: &Vec<usize>| {
+            let (mut left, mut ordered) = (update.clone(), Vec::new());
+            while !left.is_empty() {
+                let first = left
+                    .iter()
+                    .position(|&page| {
+                        !left.iter().any(|&other| rules.contains(&(other, page)))
+                    })
+                    .unwrap();
+                ordered.push(left.remove(first));
+            }
+            ordered
+        };
+        let middle = |update: Vec<usize>| update[update.len() / 2];
+        (
+            updates.iter().filter(|u| in_order(u)).cloned().map(middle).sum(),
+            updates.iter().filter(|u| !in_order(u)).map(reorder).map(middle).sum(),
+        )
+    }
+    proptest! {
+        #[test]
+        fn prop_matches_brute_force((order, updates) in arb_print_queue()) {
+            let rules: HashSet<(usize, usize)> = (0..order.len())
+                .flat_map(|i| (i + 1..order.len()).map(move |j| (i, j)))
+                .map(|(i, j)| (order[i], order[j]))
+                .collect();
+            let mut input: String = rules
+                .iter()
+                .map(|(first, latter)| format!("{first}|{latter}\n"))
+                .collect();
+            input.push('\n');
+            for update in &updates {
+                let pages: Vec<String> = update.iter().map(|page| page.to_string()).collect();
+                input += &(pages.join(",") + "\n");
+            }
+            let queue = Day5::parse(&input).unwrap();
+            let (correct, reordered) = brute_print_queue(&rules, &updates);
+            prop_assert_eq!(Day5::part1(&queue), Answer::from(correct));
+            prop_assert_eq!(Day5::part2(&queue), Answer::from(reordered));
+        }
+    }
+}
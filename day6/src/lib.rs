@@ -0,0 +1,269 @@
+use aoc_common::geometry::{Direction, IPoint};
+use aoc_common::grid::Cell;
+use aoc_common::parse::Lines;
+use aoc_common::visualize::{Animate, Color, Frame, Visualize};
+use aoc_common::{Answer, Grid, ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub struct Day6;
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = GuarddMap;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(map: &Self::Parsed) -> Answer {
+        map.clone().visited_postions().into()
+    }
+    fn part2(map: &Self::Parsed) -> Answer {
+        map.put_obstacles().into()
+    }
+}
+
+type Position = IPoint;
+
+#[derive(Clone)]
+struct Arena {
+    obstacles: Grid<bool>,
+}
+impl Arena {
+    fn cell(position: Position) -> Option<Cell> {
+        position.unsigned()
+    }
+    pub fn contains(&self, position: Position) -> bool {
+        Self::cell(position).is_some_and(|cell| self.obstacles.contains(cell))
+    }
+    pub fn is_obstacle(&self, position: Position) -> bool {
+        Self::cell(position).and_then(|cell| self.obstacles.get(cell)) == Some(&true)
+    }
+    pub fn put_obstacle(&mut self, position: Position) {
+        if let Some(cell) = Self::cell(position) {
+            self.obstacles.set(cell, true);
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct InfiniteLoopError {}
+#[derive(Clone)]
+pub struct GuarddMap {
+    guard_start: Position,
+    guard_direction: Direction,
+    arena: Arena,
+    visited: HashSet<Position>,
+}
+impl Display for GuarddMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.arena.obstacles.height() as isize {
+            for x in 0..self.arena.obstacles.width() as isize {
+                if self.arena.is_obstacle(Position{x,y}){
+                    write!(f, "#")?;
+                } else if self.guard_start == (Position{x,y}) {
+                    write!(f, "^")?;
+                } else if self.visited.contains(&Position{x,y}){
+                    write!(f, "X")?;
+                }
+                else {
+                    write!(f, ".")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+impl GuarddMap {
+    pub fn visited_postions(&mut self) -> usize {
+        let mut guard_position = self.guard_start;
+        let mut guard_direction = self.guard_direction;
+        loop {
+            if !self.arena.contains(guard_position){
+                return self.visited.len()
+            }
+            if self.arena.is_obstacle(guard_position+guard_direction){
+                guard_direction = guard_direction.rotate_clockwise();
+            } else {
+                self.visited.insert(guard_position);
+                guard_position+=guard_direction;
+            }
+        }
+    }
+    pub fn move_guard(&self) -> Result<(), InfiniteLoopError> {
+        let mut guard_position = self.guard_start;
+        let mut guard_direction = self.guard_direction;
+        let mut path = Vec::new();
+        loop {
+            if !self.arena.contains(guard_position){
+                return Ok(())
+            }
+            if self.arena.is_obstacle(guard_position+guard_direction){
+                guard_direction = guard_direction.rotate_clockwise();
+            } else {
+                guard_position+=guard_direction;
+            }
+            if path.contains(&(guard_position, guard_direction)){
+                return Err(InfiniteLoopError {});
+            }
+            path.push((guard_position, guard_direction));
+        }
+    }
+    pub fn put_obstacles(&self) -> usize {
+        let mut guard_position = self.guard_start;
+        let mut guard_direction = self.guard_direction;
+        let mut candidates = Vec::new();
+        loop {
+            if self.guard_start != guard_position+guard_direction {
+                candidates.push((guard_position, guard_direction));
+            }
+            if self.arena.is_obstacle(guard_position+guard_direction){
+                guard_direction = guard_direction.rotate_clockwise();
+            } else {
+                guard_position+=guard_direction;
+            }
+            if !self.arena.contains(guard_position){
+                break;
+            }
+        }
+        // Every candidate obstacle is simulated on its own copy of the map
+        let loops = |&(guard_position, guard_direction): &(Position, Direction)| {
+            let mut possible_map = self.clone();
+            possible_map.arena.put_obstacle(guard_position+guard_direction);
+            possible_map.guard_start = guard_position;
+            possible_map.guard_direction = guard_direction;
+            possible_map.move_guard() == Err(InfiniteLoopError {})
+        };
+        #[cfg(feature = "parallel")]
+        let candidates = candidates.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let candidates = candidates.iter();
+        candidates
+            .filter(|candidate| loops(candidate))
+            .map(|&(guard_position, _)| guard_position)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
+impl Animate for Day6 {
+    fn animate(map: GuarddMap) -> Box<dyn Visualize> {
+        Box::new(GuardWalk{
+            position: map.guard_start,
+            direction: map.guard_direction,
+            map,
+        })
+    }
+}
+/// The guard's patrol, one move or turn per step.
+struct GuardWalk {
+    map: GuarddMap,
+    position: Position,
+    direction: Direction,
+}
+impl Visualize for GuardWalk {
+    fn frame(&self) -> Frame {
+        let caption = format!("Visited: {}", self.map.visited.len());
+        let mut frame = Frame::from_display(&self.map).with_caption(caption);
+        frame.paint("#", Color::Gray);
+        frame.paint("X", Color::Yellow);
+        if let Some(cell) = self.position.unsigned() {
+            frame.set(cell, self.direction.to_string().chars().next().unwrap(), Color::Red);
+        }
+        frame
+    }
+    fn step(&mut self) -> bool {
+        if !self.map.arena.contains(self.position){
+            return false
+        }
+        if self.map.arena.is_obstacle(self.position+self.direction){
+            self.direction = self.direction.rotate_clockwise();
+        } else {
+            self.map.visited.insert(self.position);
+            self.position+=self.direction;
+        }
+        true
+    }
+}
+impl FromStr for GuarddMap {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut lines = Lines::new(input);
+        let tile = |char| "#.^".contains(char).then_some(char);
+        let map = Grid::parse(&mut lines, tile, "one of '.', '#', '^'")?;
+        let guard_start = map
+            .position(|&char| char == '^')
+            .map(|cell| cell.signed())
+            .ok_or_else(|| {
+                ParseError::end_of_input(lines.number() + 1, 1, "a guard '^' on the map")
+            })?;
+        let arena = Arena{obstacles: map.map(|&char| char == '#')};
+        Ok(Self{
+            guard_start,
+            guard_direction: Direction::Up,
+            arena,
+            visited: HashSet::new()
+        })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use crate::{Day6, GuarddMap, InfiniteLoopError, Position};
+    use aoc_common::geometry::Direction;
+    use aoc_common::parse_input;
+    use aoc_common::visualize::Animate;
+
+    #[test]
+    fn test_direction() {
+        let direction = Direction::Down;
+        assert_eq!(direction.rotate_clockwise(), Direction::Left);
+        assert_eq!(direction.rotate_clockwise().rotate_clockwise(), Direction::Up);
+        assert_eq!(direction.rotate_clockwise().rotate_clockwise().rotate_clockwise(), Direction::Right);
+
+        let mut position = Position{x: 0, y: 0};
+        position+=direction;
+        assert_eq!(position, Position{ x: 0, y: 1 });
+        position+=direction;
+        assert_eq!(position, Position{ x: 0, y: 2 })
+    }
+    #[test]
+    fn test_part1() {
+        let mut map: GuarddMap = parse_input!("example-input.txt").unwrap();
+        assert_eq!(map.visited_postions(), 41);
+    }
+    #[test]
+    fn test_printing_press() {
+        let mut map: GuarddMap = parse_input!("example-input.txt").unwrap();
+        map.arena.put_obstacle(Position{x:3, y:6});
+        assert_eq!(map.move_guard(), Err(InfiniteLoopError {}));
+    }
+    #[test]
+    fn test_failed_suit_prototypes() {
+        let mut map: GuarddMap = parse_input!("example-input.txt").unwrap();
+        map.arena.put_obstacle(Position{x:6, y:7});
+        assert_eq!(map.move_guard(), Err(InfiniteLoopError {}));
+    }
+
+    #[test]
+    fn test_tank_glue() {
+        let mut map: GuarddMap = parse_input!("example-input.txt").unwrap();
+        map.arena.put_obstacle(Position{x:7, y:9});
+        assert_eq!(map.move_guard(), Err(InfiniteLoopError {}));
+    }
+    #[test]
+    fn test_put_obstacles() {
+
+        let map: GuarddMap = parse_input!("example-input.txt").unwrap();
+        assert_eq!(map.put_obstacles(), 6);
+    }
+    #[test]
+    fn test_visualize() {
+        let map: GuarddMap = parse_input!("example-input.txt").unwrap();
+        let mut walk = Day6::animate(map);
+        assert_eq!(walk.frame().to_string().lines().nth(7), Some(".#..^....."));
+        while walk.step() {}
+        assert_eq!(walk.frame().caption, "Visited: 41");
+    }
+}
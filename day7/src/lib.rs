@@ -0,0 +1,230 @@
+use aoc_common::parse::{Line, parse_lines};
+use aoc_common::{Answer, ParseError, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+pub struct Day7;
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = Vec<CalibrationEquation>;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse(input)
+    }
+    fn part1(calibration: &Self::Parsed) -> Answer {
+        total_calibration_result(calibration).into()
+    }
+    fn part2(calibration: &Self::Parsed) -> Answer {
+        total_calibration_result_part2(calibration).into()
+    }
+}
+
+#[derive(Debug)]
+enum Operator {
+    Add,
+    Multiply,
+    Concatenate,
+}
+
+#[derive(Debug, Clone)]
+pub struct CalibrationEquation {
+    test_value: u64,
+    values: Vec<u64>,
+}
+fn evaluate_operator(operator: &Operator, left: u64, right: u64) -> Option<u64> {
+    match operator {
+        Operator::Add => left.checked_add(right),
+        Operator::Multiply => left.checked_mul(right),
+        Operator::Concatenate => {
+            let digits = right.checked_ilog10().map_or(1, |log| log + 1);
+            left.checked_mul(10u64.checked_pow(digits)?)?
+                .checked_add(right)
+        }
+    }
+}
+/// Whether `operators` put between `result` and the `values` can give `test_value`. Results too
+/// large for a `u64` can't.
+fn test_operators(values: &[u64], test_value: u64, result: u64, operators: &[Operator]) -> bool {
+    // Only multiplying by 0 makes the result smaller
+    if result > test_value && !values.contains(&0) {
+        return false;
+    }
+    match values.split_first() {
+        None => result == test_value,
+        Some((&value, values)) => operators.iter().any(|operator| {
+            evaluate_operator(operator, result, value)
+                .is_some_and(|result| test_operators(values, test_value, result, operators))
+        }),
+    }
+}
+
+impl CalibrationEquation {
+    fn solvable(&self, operators: &[Operator]) -> bool {
+        let (&first, values) = self.values.split_first().expect("parsed with a value");
+        test_operators(values, self.test_value, first, operators)
+    }
+    pub fn can_construct_equation(&self) -> bool {
+        self.solvable(&[Operator::Add, Operator::Multiply])
+    }
+    pub fn can_construct_equation_part2(&self) -> bool {
+        self.solvable(&[Operator::Add, Operator::Multiply, Operator::Concatenate])
+    }
+}
+
+pub fn total_calibration_result(calibration: &[CalibrationEquation]) -> u64 {
+    sum_solvable(calibration, CalibrationEquation::can_construct_equation)
+}
+pub fn total_calibration_result_part2(calibration: &[CalibrationEquation]) -> u64 {
+    sum_solvable(
+        calibration,
+        CalibrationEquation::can_construct_equation_part2,
+    )
+}
+/// Equations are independent, the `parallel` feature checks them on every core.
+fn sum_solvable(
+    calibration: &[CalibrationEquation],
+    solvable: fn(&CalibrationEquation) -> bool,
+) -> u64 {
+    #[cfg(feature = "parallel")]
+    let equations = calibration.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let equations = calibration.iter();
+    equations
+        .filter(|c| solvable(c))
+        .map(|c| c.test_value)
+        .sum()
+}
+impl CalibrationEquation {
+    fn parse(line: Line) -> Result<Self, ParseError> {
+        let (test_val, vals) = line.split_once(":", "':' after the test value")?;
+        let values: Vec<u64> = vals
+            .split_whitespace()
+            .map(|x| line.parse(x, "a number"))
+            .collect::<Result<_, _>>()?;
+        if values.is_empty() {
+            return Err(line.error(vals, "at least one number"));
+        }
+        Ok(Self {
+            test_value: line.parse(test_val.trim(), "a test value")?,
+            values,
+        })
+    }
+}
+pub fn parse(input: &str) -> Result<Vec<CalibrationEquation>, ParseError> {
+    parse_lines(input, CalibrationEquation::parse)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::Day7;
+    use crate::{
+        Operator, evaluate_operator, parse, test_operators, total_calibration_result,
+        total_calibration_result_part2,
+    };
+    use aoc_common::input::read_to_string;
+    use aoc_common::{Answer, Solution, read_input};
+    use proptest::prelude::*;
+
+    fn example() -> String {
+        read_to_string(read_input!("example-input.txt").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_operators_1() {
+        let part1 = [Operator::Add, Operator::Multiply];
+        assert!(test_operators(&[40, 27], 3267, 81, &part1));
+        let part2 = [Operator::Add, Operator::Multiply, Operator::Concatenate];
+        assert!(test_operators(&[8, 6, 15], 7290, 6, &part2));
+        assert!(test_operators(&[8, 14], 192, 17, &part2));
+        // a single value, and a zero bringing the result back down
+        assert!(test_operators(&[], 7, 7, &part1));
+        assert!(test_operators(&[0, 3], 3, 5, &part1));
+        assert!(!test_operators(&[u64::MAX], 1, 2, &part2));
+    }
+    #[test]
+    fn test_part1() {
+        let calibrations = parse(&example()).unwrap();
+        assert_eq!(total_calibration_result(&calibrations), 3749)
+    }
+    #[test]
+    fn test_part2() {
+        let calibrations = parse(&example()).unwrap();
+        assert_eq!(evaluate_operator(&Operator::Concatenate, 10, 1), Some(101));
+        assert_eq!(evaluate_operator(&Operator::Concatenate, 1, 0), Some(10));
+        assert_eq!(evaluate_operator(&Operator::Concatenate, u64::MAX, 1), None);
+        assert_eq!(total_calibration_result_part2(&calibrations), 11387)
+    }
+    #[test]
+    fn test_parse_error() {
+        let error = parse("190: 10 19\n3267 81 40 27").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 14));
+        let error = parse("190: 10 1x9").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 9: expected a number, found \"1x9\""
+        );
+        let error = parse("7: 7\n7:").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    /// Equations of 1 to 6 numbers, some of them 0, solvable about half of the time: the test
+    /// value is either random or the result of random operators.
+    fn arb_equations() -> impl Strategy<Value = Vec<(u64, Vec<u64>)>> {
+        let equation = prop::collection::vec(0..20u64, 1..7).prop_flat_map(|values| {
+            let operators = prop::collection::vec(0..3usize, values.len() - 1);
+            (Just(values), operators, prop::option::of(1..5000u64))
+        });
+        let equation = equation.prop_map(|(values, operators, random)| {
+            let solved = operators
+                .iter()
+                .zip(&values[1..])
+                .fold(values[0], |result, (&operator, &value)| {
+                    apply(operator, result, value)
+                });
+            (random.unwrap_or(solved), values)
+        });
+        prop::collection::vec(equation, 0..8)
+    }
+    fn apply(operator: usize, left: u64, right: u64) -> u64 {
+        match operator {
+            0 => left + right,
+            1 => left * right,
+            _ => format!("{left}{right}").parse().unwrap(),
+        }
+    }
+    /// Tries every combination of the first `operators` operators.
+    fn brute_solvable(test_value: u64, values: &[u64], operators: usize) -> bool {
+        let slots = values.len() as u32 - 1;
+        (0..operators.pow(slots)).any(|mut combination| {
+            let result = values[1..].iter().fold(values[0], |result, &value| {
+                let operator = combination % operators;
+                combination /= operators;
+                apply(operator, result, value)
+            });
+            result == test_value
+        })
+    }
+    proptest! {
+        #[test]
+        fn prop_matches_brute_force(equations in arb_equations()) {
+            let input: String = equations
+                .iter()
+                .map(|(test_value, values)| {
+                    let values: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+                    format!("{test_value}: {}\n", values.join(" "))
+                })
+                .collect();
+            let calibration = Day7::parse(&input).unwrap();
+            let total = |operators| {
+                equations
+                    .iter()
+                    .filter(|(test_value, values)| brute_solvable(*test_value, values, operators))
+                    .map(|(test_value, _)| test_value)
+                    .sum::<u64>()
+            };
+            prop_assert_eq!(Day7::part1(&calibration), Answer::from(total(2)));
+            prop_assert_eq!(Day7::part2(&calibration), Answer::from(total(3)));
+        }
+    }
+}
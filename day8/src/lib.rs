@@ -0,0 +1,168 @@
+use aoc_common::geometry::IPoint;
+use aoc_common::grid::Cell;
+use aoc_common::parse::Lines;
+use aoc_common::{Answer, Grid, ParseError, Solution};
+use gcd::Gcd;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use tracing::{debug, trace};
+
+pub struct Day8;
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = AntennasMap;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(map: &Self::Parsed) -> Answer {
+        map.unique_antinodes().into()
+    }
+    fn part2(map: &Self::Parsed) -> Answer {
+        map.rezonance_harmonics().into()
+    }
+}
+pub type Position = IPoint;
+type Vector = IPoint;
+pub fn antinodes(first: Position, second: Position) -> (Position, Position) {
+    let vector: Vector = second - first;
+    (first - vector, second + vector)
+}
+fn cell(position: &Position) -> Option<Cell> {
+    position.unsigned()
+}
+#[derive(Debug, Default)]
+pub struct AntennasMap {
+    map: Grid<char>,
+    antennas: HashMap<char, Vec<Position>>,
+}
+impl AntennasMap {
+    fn in_dimensions(&self, position: &Position) -> bool {
+        cell(position).is_some_and(|cell| self.map.contains(cell))
+    }
+    pub fn unique_antinodes(&self) -> usize {
+        self.antennas
+            .iter()
+            .flat_map({
+                |(_, positions)| {
+                    positions
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(idx, pos)| match positions.split_at_checked(idx + 1) {
+                            Some((_, rest)) => rest
+                                .iter()
+                                .map(|sec| antinodes(*pos, *sec))
+                                .flat_map(|(a1, a2)| vec![a1, a2])
+                                .collect::<Vec<_>>(),
+                            None => {
+                                vec![]
+                            }
+                        })
+                }
+            })
+            .filter(|pos| self.in_dimensions(pos))
+            .collect::<HashSet<_>>()
+            .len()
+    }
+    fn antinodes(&self, first: &Position, second: &Position) -> Vec<Position> {
+        let tmp: Vector = *second - *first;
+        let gcd = (tmp.x.unsigned_abs() as u64).gcd(tmp.y.unsigned_abs() as u64) as isize;
+        let vector = Vector {
+            x: tmp.x / gcd,
+            y: tmp.y / gcd,
+        };
+        trace!(?tmp, gcd, ?vector, "antinode vector");
+        let reverse = -vector;
+        let mut out = Vec::new();
+        let mut first = *first;
+        loop {
+            if self.in_dimensions(&first) {
+                out.push(first);
+            } else {
+                break;
+            }
+            first += reverse;
+        }
+        let mut second = *second;
+        loop {
+            if self.in_dimensions(&second) {
+                out.push(second);
+            } else {
+                break;
+            }
+            second += vector;
+        }
+        out
+    }
+    pub fn rezonance_harmonics(&self) -> usize {
+        let antinodes = self
+            .antennas
+            .iter()
+            .flat_map({
+                |(_, positions)| {
+                    positions
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(idx, pos)| match positions.split_at_checked(idx + 1) {
+                            Some((_, rest)) => rest
+                                .iter()
+                                .flat_map(|sec| self.antinodes(pos, sec))
+                                .collect::<Vec<_>>(),
+                            None => {
+                                vec![]
+                            }
+                        })
+                }
+            })
+            .filter(|pos| self.in_dimensions(pos))
+            .collect::<HashSet<_>>();
+        debug!(map = %self, antinodes = pretty_print(self, &antinodes), "resonant harmonics");
+        antinodes.len()
+    }
+}
+impl Display for AntennasMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+pub fn pretty_print(map: &AntennasMap, antinodes: &HashSet<Position>) -> String {
+    let mut marked = map.map.clone();
+    for antinode in antinodes.iter().filter_map(cell) {
+        if marked.get(antinode) == Some(&'.') {
+            marked.set(antinode, '#');
+        }
+    }
+    marked.to_string()
+}
+impl FromStr for AntennasMap {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(
+            &mut Lines::new(input),
+            |char| (char == '.' || char.is_alphanumeric()).then_some(char),
+            "'.' or an antenna frequency",
+        )?;
+        let mut antennas: HashMap<char, Vec<Position>> = HashMap::new();
+        for (cell, &char) in map.iter().filter(|&(_, &char)| char != '.') {
+            antennas.entry(char).or_default().push(cell.signed());
+        }
+        Ok(Self { map, antennas })
+    }
+}
+#[cfg(test)]
+mod tests {
+    use crate::AntennasMap;
+    use aoc_common::parse_input;
+
+    #[test]
+    fn test_part1() {
+        let antennas_map: AntennasMap = parse_input!("example-input.txt").unwrap();
+        assert_eq!(antennas_map.unique_antinodes(), 14);
+    }
+    #[test]
+    fn test_part2() {
+        let antennas_map: AntennasMap = parse_input!("example-input.txt").unwrap();
+        assert_eq!(antennas_map.rezonance_harmonics(), 34);
+    }
+}
@@ -1,17 +1,25 @@
-#![feature(unsigned_signed_diff)]
-
-
+use aoc_common::parse::Lines;
+use aoc_common::{Answer, ParseError, Solution};
 use std::fmt::Display;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::PathBuf;
+use std::str::FromStr;
+use tracing::{instrument, trace};
 
-fn main() {
-    let _reader = read_input("puzzle-input.txt");
-    let mut disk_map = DiskMap::from(_reader);
-    println!("Compacted checksum: {}", disk_map.compact());
-    println!("Defragmented checksum: {}", disk_map.defragment());
+pub struct Day9;
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = DiskMap;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        input.parse()
+    }
+    fn part1(disk: &Self::Parsed) -> Answer {
+        disk.compact().into()
+    }
+    fn part2(disk: &Self::Parsed) -> Answer {
+        disk.clone().defragment().into()
+    }
 }
+
 #[derive(Debug,Copy, Clone)]
 enum Fragment {
     Used { file_id: usize, blocks: u8, moved: bool},
@@ -53,23 +61,24 @@ impl From<(usize, u8)> for Fragment {
         }
     }
 }
-struct DiskMap{
+#[derive(Clone)]
+pub struct DiskMap{
     fragments: Vec<Fragment>
 }
 impl DiskMap {
-    #[allow(dead_code)]
-    fn print_blocks(blocks: &Vec<&Fragment>) -> String {
+    fn print_blocks(blocks: &[&Fragment]) -> String {
         blocks.iter().map(|&f| match f {
             Fragment::Free {  blocks: _ } => ".".to_string(),
             Fragment::Used { file_id: id, blocks: _, moved: _} => format!("{}", id)
         }).collect::<String>()
     }
+    #[instrument(skip_all)]
     pub fn compact(&self) -> usize {
         let mut blocks = Vec::new();
         for frag in &self.fragments {
             blocks.append(&mut vec![frag; frag.blocks() as usize]);
         }
-        // println!("Disk layout\n{}",Self::print_blocks(&blocks));
+        trace!(layout = Self::print_blocks(&blocks), "disk layout");
         let empty_indices = blocks.iter().enumerate().filter_map(|(idx,f)| match f {
             Fragment::Free {  blocks: _ } => Some(idx),
             _ => None
@@ -79,15 +88,12 @@ impl DiskMap {
             _ => None
         }).collect::<Vec<_>>();
         for (empty,file) in empty_indices.iter().zip(file_indices.iter()) {
-            if blocks.iter().rev().take_while(|frag|match frag {
-                Fragment::Free { blocks: _} => true,
-                _ => false
-            }).count() == empty_indices.len(){
+            if blocks.iter().rev().take_while(|frag|matches!(frag, Fragment::Free { blocks: _})).count() == empty_indices.len(){
                 break;
             }
-            // println!("Swapping {} with {}", blocks[*empty], blocks[*file]);
+            trace!(free = %blocks[*empty], file = %blocks[*file], "swapping");
             blocks.swap(*empty,*file);
-            // println!("Disk layout\n{}",Self::print_blocks(&blocks));
+            trace!(layout = Self::print_blocks(&blocks), "disk layout");
         }
         blocks.into_iter().map_while(|frag|match frag {
             Fragment::Used { file_id: id, blocks: _, moved: _} => Some(id),
@@ -113,6 +119,7 @@ impl DiskMap {
         }
         None
     }
+    #[instrument(skip_all)]
     pub fn defragment(&mut self) -> usize {
         while let Some((file_idx, free_idx)) = self.can_move_file() {
             let mut file = self.fragments[file_idx];
@@ -132,70 +139,115 @@ impl DiskMap {
                 _ => unreachable!()
             }
             self.fragments.insert(free_idx, file);
-            // println!("{}",self);
+            trace!(disk_map = %self, "moved file");
         }
         let mut blocks = Vec::new();
         for frag in &self.fragments {
             blocks.append(&mut vec![frag; frag.blocks() as usize]);
         }
-        // dbg!(&blocks);
         blocks.iter().enumerate().filter_map(|(idx,frag)|match frag {
             Fragment::Used { file_id, blocks: _,moved: _} => Some(file_id*idx),
             _ => None
-        }).fold(0, |acc, mult|acc+mult)
+        }).sum()
     }
 }
-// fn print_layout(fragments: &[Fragment]) -> String {
-//     fragments.iter().map(|f| format!("{}", f)).collect::<String>()
-// }
 impl Display for DiskMap {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         for frag in &self.fragments {
             write!(f, "{}", frag)?;
         }
-        write!(f, "\n")?;
+        writeln!(f)?;
         Ok(())
     }
 }
 
-impl<Reader> From<Reader> for DiskMap
-where
-    Reader: BufRead,
-{
-    fn from(mut value: Reader) -> Self {
-        let mut chars = Vec::new();
-        value.read_to_end(chars.as_mut()).unwrap();
-        Self{
-            fragments: chars.into_iter()
-                .map(|char|(char as char).to_digit(10).unwrap() as u8)
+impl FromStr for DiskMap {
+    type Err = ParseError;
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let line = Lines::new(input).expect("a disk map")?;
+        let digits = line.text.chars()
+            .enumerate()
+            .map(|(idx, char)| match char.to_digit(10) {
+                Some(digit) => Ok(digit as u8),
+                None => Err(line.error_at_char(idx, "a digit")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self{
+            fragments: digits.into_iter()
                 .enumerate()
                 .map(Fragment::from )
                 .collect()
-        }
+        })
     }
 }
 
-fn read_input(name: &str) -> BufReader<File> {
-    let mut file = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-    file.push(format!("resources/{name}"));
-    BufReader::new(File::open(file).unwrap())
-}
 #[cfg(test)]
 mod tests {
 
-    use crate::{read_input, DiskMap};
+    use crate::{Day9, DiskMap};
+    use aoc_common::{parse_input, Answer, Solution};
+    use proptest::prelude::*;
 
     #[test]
     fn test_part1() {
-        let _reader = read_input("example-input.txt");
-        let disk_map = DiskMap::from(_reader);
-        // println!("{}",disk_map);
+        let disk_map: DiskMap = parse_input!("example-input.txt").unwrap();
         assert_eq!(disk_map.compact(), 1928)
     }
     #[test]
     fn test_part2() {
-        let _reader = read_input("example-input.txt");
-        let mut disk_map = DiskMap::from(_reader);
+        let mut disk_map: DiskMap = parse_input!("example-input.txt").unwrap();
         assert_eq!( disk_map.defragment(), 2858)
     }
+
+    fn arb_disk_map() -> impl Strategy<Value = Vec<u8>> {
+        prop::collection::vec((1..=9u8, 0..=9u8), 1..10).prop_map(|spans| {
+            spans.into_iter().flat_map(|(used, free)| [used, free]).collect()
+        })
+    }
+    fn blocks(digits: &[u8]) -> Vec<Option<usize>> {
+        digits.iter().enumerate()
+            .flat_map(|(idx, &len)| {
+                std::iter::repeat_n((idx % 2 == 0).then_some(idx / 2), len as usize)
+            })
+            .collect()
+    }
+    fn checksum(blocks: &[Option<usize>]) -> usize {
+        blocks.iter().enumerate().map(|(idx, id)| idx * id.unwrap_or(0)).sum()
+    }
+    fn brute_compact(digits: &[u8]) -> usize {
+        let mut blocks = blocks(digits);
+        while let (Some(free), Some(used)) = (
+            blocks.iter().position(Option::is_none),
+            blocks.iter().rposition(Option::is_some),
+        ) {
+            if free > used {
+                break;
+            }
+            blocks.swap(free, used);
+        }
+        checksum(&blocks)
+    }
+    fn brute_defragment(digits: &[u8]) -> usize {
+        let mut blocks = blocks(digits);
+        for id in (0..digits.len().div_ceil(2)).rev() {
+            let start = blocks.iter().position(|&block| block == Some(id)).unwrap();
+            let len = digits[id * 2] as usize;
+            let fits = |to: &usize| blocks[*to..*to + len].iter().all(Option::is_none);
+            if let Some(to) = (0..start).find(fits) {
+                for offset in 0..len {
+                    blocks.swap(to + offset, start + offset);
+                }
+            }
+        }
+        checksum(&blocks)
+    }
+    proptest! {
+        #[test]
+        fn prop_matches_brute_force(digits in arb_disk_map()) {
+            let input: String = digits.iter().map(|digit| char::from(b'0' + digit)).collect();
+            let disk_map = Day9::parse(&input).unwrap();
+            prop_assert_eq!(Day9::part1(&disk_map), Answer::from(brute_compact(&digits)));
+            prop_assert_eq!(Day9::part2(&disk_map), Answer::from(brute_defragment(&digits)));
+        }
+    }
 }
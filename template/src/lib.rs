@@ -0,0 +1,40 @@
+use aoc_common::parse::parse_lines;
+use aoc_common::{Answer, ParseError, Solution};
+
+pub struct Template;
+impl Solution for Template {
+    const DAY: u8 = 0;
+    const RESOURCES: &'static str = aoc_common::resources_dir!();
+    type Parsed = Vec<String>;
+    fn parse(input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_lines(input, |line| Ok(line.text.to_string()))
+    }
+    fn part1(_parsed: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+    fn part2(_parsed: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Template;
+    use aoc_common::input::read_to_string;
+    use aoc_common::{Answer, Solution, read_input};
+
+    fn example() -> <Template as Solution>::Parsed {
+        let input = read_to_string(read_input!("example-input.txt").unwrap()).unwrap();
+        Template::parse(&input).unwrap()
+    }
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_part1() {
+        assert_eq!(Template::part1(&example()), Answer::from(0usize));
+    }
+    #[test]
+    #[ignore = "not solved yet"]
+    fn test_part2() {
+        assert_eq!(Template::part2(&example()), Answer::from(0usize));
+    }
+}